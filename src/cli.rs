@@ -0,0 +1,71 @@
+use std::path::PathBuf;
+
+pub const USAGE: &str = "gebruik: suus-game-hub [--record <bestand.cast>] [--serve <adres:poort>]
+       suus-game-hub words check [pakket...]
+       suus-game-hub words stats [pakket...]
+       suus-game-hub words import <bestand.txt|.csv> <pakket>";
+
+// The word list tools, packs are files in data/hangman or a path to one
+pub enum WordsCommand {
+    // no packs means every pack
+    Check(Vec<PathBuf>),
+    Stats(Vec<PathBuf>),
+    Import { source: PathBuf, pack: String },
+}
+
+#[derive(Default)]
+pub struct Options {
+    pub record: Option<PathBuf>,
+    pub serve: Option<String>,
+    pub words: Option<WordsCommand>,
+}
+
+pub fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Options, String> {
+    let mut options = Options::default();
+    let mut args = args.into_iter().peekable();
+
+    if args.peek().is_some_and(|arg| arg == "words") {
+        args.next();
+        options.words = Some(parse_words_command(args.collect())?);
+        return Ok(options);
+    }
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--record" => {
+                let path = args
+                    .next()
+                    .ok_or_else(|| format!("--record heeft een bestandsnaam nodig\n{}", USAGE))?;
+                options.record = Some(PathBuf::from(path));
+            }
+            "--serve" => {
+                let addr = args
+                    .next()
+                    .ok_or_else(|| format!("--serve heeft een adres nodig\n{}", USAGE))?;
+                options.serve = Some(addr);
+            }
+            "-h" | "--help" => return Err(USAGE.to_string()),
+            _ => return Err(format!("onbekend argument: {}\n{}", arg, USAGE)),
+        }
+    }
+
+    Ok(options)
+}
+
+fn parse_words_command(args: Vec<String>) -> Result<WordsCommand, String> {
+    let Some((command, rest)) = args.split_first() else {
+        return Err(format!("words heeft check, stats of import nodig\n{}", USAGE));
+    };
+    let packs = || rest.iter().map(PathBuf::from).collect();
+
+    match (command.as_str(), rest) {
+        ("check", _) => Ok(WordsCommand::Check(packs())),
+        ("stats", _) => Ok(WordsCommand::Stats(packs())),
+        ("import", [source, pack]) => Ok(WordsCommand::Import {
+            source: PathBuf::from(source),
+            pack: pack.clone(),
+        }),
+        ("import", _) => Err(format!("import heeft een bestand en een pakket nodig\n{}", USAGE)),
+        _ => Err(format!("onbekend words commando: {}\n{}", command, USAGE)),
+    }
+}
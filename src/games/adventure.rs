@@ -1,4 +1,4 @@
-use crate::games::Game;
+use crate::games::{Game, GameOutcome};
 use crate::ui::adventure_ui;
 use crate::utils::image as image_utils;
 
@@ -55,6 +55,7 @@ pub struct AdventureStats {
 
 pub struct Adventure {
     scenes: HashMap<String, Scene>,
+    first_scene: String,
     current_scene: String,
 
     log: Vec<String>,
@@ -69,6 +70,10 @@ pub struct Adventure {
     pub art_shown: bool,
 
     pub stats: AdventureStats,
+
+    // scene that ends the round when reached, e.g. in a tournament
+    pub checkpoint: Option<String>,
+    pub game_over: bool,
 }
 
 impl Adventure {
@@ -108,6 +113,7 @@ impl Adventure {
 
         Adventure {
             scenes,
+            first_scene: first_scene_id.clone(),
             current_scene: first_scene_id,
             log: vec![first_scene_enter],
             input_buffer: String::new(),
@@ -117,11 +123,15 @@ impl Adventure {
             auto_scroll: true,
             art_shown: false,
             stats: AdventureStats { moves_done: 0 },
+            checkpoint: None,
+            game_over: false,
         }
     }
 
     pub fn start_new_game(&mut self) {
-        let first_scene_id = self.current_scene.clone();
+        let first_scene_id = self.first_scene.clone();
+        self.current_scene = first_scene_id.clone();
+        self.art_shown = false;
 
         self.log.clear();
         self.input_buffer.clear();
@@ -130,6 +140,7 @@ impl Adventure {
         self.log_scroll = 0;
         self.auto_scroll = true;
         self.stats.moves_done = 0;
+        self.game_over = false;
 
         let first = &self.scenes[&first_scene_id];
 
@@ -181,6 +192,7 @@ impl Adventure {
                 "die" => {
                     let reason = action.reason.clone().unwrap_or("You died".to_string());
                     self.log.push(format!("GAME OVER: {}", reason));
+                    self.game_over = true;
                 }
                 "show_scene_art" => {
                     self.art_shown = true;
@@ -260,6 +272,23 @@ impl Game for Adventure {
         self.start_new_game();
     }
 
+    fn outcome(&self) -> Option<GameOutcome> {
+        if self.game_over {
+            return Some(GameOutcome {
+                won: false,
+                score: 0,
+                hangman: None,
+            });
+        }
+
+        // the quicker you get there, the more it's worth
+        self.reached_checkpoint().then(|| GameOutcome {
+            won: true,
+            score: 10 + 20u32.saturating_sub(self.stats.moves_done.max(0) as u32),
+            hangman: None,
+        })
+    }
+
     fn handle_input(&mut self, key: KeyCode) {
         match key {
             KeyCode::Char(c) => {
@@ -275,13 +304,10 @@ impl Game for Adventure {
                 self.input_buffer.clear();
                 self.process_command(&input);
             }
-            KeyCode::Tab => {
-                if !self.autocomplete_matches.is_empty() {
-                    self.autocomplete_index =
-                        (self.autocomplete_index + 1) % self.autocomplete_matches.len();
-                    self.input_buffer =
-                        self.autocomplete_matches[self.autocomplete_index].to_string();
-                }
+            KeyCode::Tab if !self.autocomplete_matches.is_empty() => {
+                self.autocomplete_index =
+                    (self.autocomplete_index + 1) % self.autocomplete_matches.len();
+                self.input_buffer = self.autocomplete_matches[self.autocomplete_index].to_string();
             }
             KeyCode::Up => self.scroll_up(),
             KeyCode::Down => self.scroll_down(),
@@ -291,6 +317,10 @@ impl Game for Adventure {
 }
 
 impl Adventure {
+    pub fn reached_checkpoint(&self) -> bool {
+        self.checkpoint.as_ref() == Some(&self.current_scene)
+    }
+
     pub fn log(&self) -> &Vec<String> {
         &self.log
     }
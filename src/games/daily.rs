@@ -0,0 +1,156 @@
+use crate::games::word_packs::{WordEntry, WordPack};
+use crate::utils::time::day_key;
+use chrono::NaiveDate;
+use std::fs;
+use std::path::PathBuf;
+
+// shared results go next to the screenshots
+const SHARE_DIR: &str = "screenshots";
+
+pub const HIT: &str = "🟩";
+pub const MISS: &str = "🟥";
+pub const HINT: &str = "💡";
+pub const SOLVED: &str = "🎯";
+pub const WRONG_SOLVE: &str = "❌";
+
+// FNV-1a, spelled out so the word of the day stays the same on every machine
+// and with every Rust version
+fn stable_hash(text: &str) -> u64 {
+    text.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+// The same word for everyone with the same packs, picked from the date and
+// the pack. Sorted first, so the order of the pack files doesn't matter.
+pub fn daily_entry<'a>(
+    entries: impl Iterator<Item = (&'a WordPack, &'a WordEntry)>,
+    day: &str,
+    pack: &str,
+) -> Option<(&'a WordPack, &'a WordEntry)> {
+    let mut entries: Vec<_> = entries.collect();
+    entries.sort_by(|a, b| a.1.word().cmp(b.1.word()));
+    entries.dedup_by(|a, b| a.1.word() == b.1.word());
+
+    if entries.is_empty() {
+        return None;
+    }
+    let index = stable_hash(&format!("{}/{}", day, pack)) % entries.len() as u64;
+    Some(entries[index as usize])
+}
+
+// Playing the word of the day, once per day per pack
+pub struct Daily {
+    pub date: NaiveDate,
+    pub pack: String,
+    // one emoji per guess, in the order they were made
+    pub marks: String,
+    // the streak you'll have once this one is won
+    pub streak_if_won: u32,
+    // what happened when sharing, shown in the end popup
+    pub status: Option<String>,
+    // the summary, until the hub sends it to the player's terminal
+    pub clipboard: Option<String>,
+}
+
+impl Daily {
+    pub fn new(date: NaiveDate, pack: String, streak_if_won: u32) -> Self {
+        Self {
+            date,
+            pack,
+            marks: String::new(),
+            streak_if_won,
+            status: None,
+            clipboard: None,
+        }
+    }
+
+    pub fn day(&self) -> String {
+        day_key(self.date)
+    }
+
+    // Wordle style, nothing in it gives the word away
+    pub fn summary(&self, won: bool, wrong_guesses: u32) -> String {
+        let result = if won {
+            let mistakes = match wrong_guesses {
+                0 => "zonder fouten".to_string(),
+                1 => "met 1 fout".to_string(),
+                wrong => format!("met {} fouten", wrong),
+            };
+            format!("geraden {} • 🔥 {} op rij", mistakes, self.streak_if_won)
+        } else {
+            "niet geraden 💀".to_string()
+        };
+
+        format!("Pipis galgje van {} • {}\n{}\n{}", self.day(), self.pack, self.marks, result)
+    }
+
+    // The terminal does the copying, so it's only asked for here
+    pub fn copy(&mut self, summary: &str) {
+        self.clipboard = Some(summary.to_string());
+        self.status = Some("📋 gekopieerd!".to_string());
+    }
+
+    pub fn save(&mut self, summary: &str) {
+        let path = PathBuf::from(SHARE_DIR).join(format!("galgje-{}.txt", self.day()));
+        let saved = fs::create_dir_all(SHARE_DIR).and_then(|_| fs::write(&path, summary));
+
+        self.status = Some(match saved {
+            Ok(()) => format!("💾 opgeslagen in {}", path.display()),
+            Err(error) => format!("opslaan lukte niet: {}", error),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pack(name: &str, words: &[&str]) -> WordPack {
+        WordPack {
+            name: name.to_string(),
+            category: name.to_lowercase(),
+            language: "nl".to_string(),
+            difficulty: None,
+            words: words.iter().map(|word| WordEntry::Plain(word.to_string())).collect(),
+        }
+    }
+
+    fn entries(packs: &[WordPack]) -> impl Iterator<Item = (&WordPack, &WordEntry)> {
+        packs.iter().flat_map(|pack| pack.words.iter().map(move |entry| (pack, entry)))
+    }
+
+    fn word_of_the_day(packs: &[WordPack], day: &str) -> Option<String> {
+        daily_entry(entries(packs), day, "Alles").map(|(_, entry)| entry.word().to_string())
+    }
+
+    #[test]
+    fn the_same_word_whatever_order_the_packs_are_in() {
+        let dieren = pack("Dieren", &["kat", "hond", "kip"]);
+        let eten = pack("Eten", &["kaas", "appel", "kat"]);
+        let forwards = [dieren.clone(), eten.clone()];
+        let backwards = [eten, dieren];
+
+        for day in ["2026-10-18", "2026-10-19", "2026-10-20"] {
+            let word = word_of_the_day(&forwards, day);
+            assert!(word.is_some());
+            assert_eq!(word, word_of_the_day(&backwards, day));
+        }
+    }
+
+    #[test]
+    fn a_word_in_two_packs_counts_once() {
+        // with the duplicate left in, kat would come up twice as often
+        let once = [pack("Dieren", &["kat", "hond", "kip"])];
+        let twice = [once[0].clone(), pack("Eten", &["kat"])];
+        for day in 1..=28 {
+            let day = format!("2026-10-{:02}", day);
+            assert_eq!(word_of_the_day(&once, &day), word_of_the_day(&twice, &day));
+        }
+    }
+
+    #[test]
+    fn nothing_to_pick_from_an_empty_pack() {
+        assert!(word_of_the_day(&[pack("Leeg", &[])], "2026-10-19").is_none());
+    }
+}
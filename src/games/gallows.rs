@@ -0,0 +1,182 @@
+use serde::Deserialize;
+
+const GALLOWS_PATH: &str = "data/gallows.json";
+
+// A drawing that fills up as the misses pile up. Either a list of complete
+// frames, or a base with parts that get drawn over it one by one (spaces in a
+// part are see-through).
+#[derive(Deserialize, Clone)]
+pub struct GallowsArt {
+    pub name: String,
+    #[serde(default)]
+    pub frames: Vec<String>,
+    #[serde(default)]
+    pub base: String,
+    #[serde(default)]
+    pub parts: Vec<String>,
+}
+
+impl Default for GallowsArt {
+    // the plain gallows, in case the data file is gone
+    fn default() -> Self {
+        Self {
+            name: "galg".to_string(),
+            frames: Vec::new(),
+            base: "+---+\n|   |\n|\n|\n|\n|\n========".to_string(),
+            parts: [
+                "\n\n    o",
+                "\n\n\n    |",
+                "\n\n\n   /",
+                "\n\n\n     \\",
+                "\n\n\n\n   /",
+                "\n\n\n\n     \\",
+                "\n\n   ( )",
+                "\n\n    x",
+                "\n\n\n  _   _",
+            ]
+            .map(str::to_string)
+            .to_vec(),
+        }
+    }
+}
+
+// Draws the non-space characters of a part over the drawing
+fn overlay(drawing: &str, part: &str) -> String {
+    let mut lines: Vec<Vec<char>> = drawing.lines().map(|line| line.chars().collect()).collect();
+
+    for (row, part_line) in part.lines().enumerate() {
+        if lines.len() <= row {
+            lines.resize(row + 1, Vec::new());
+        }
+        for (column, c) in part_line.chars().enumerate().filter(|(_, c)| *c != ' ') {
+            let line = &mut lines[row];
+            if line.len() <= column {
+                line.resize(column + 1, ' ');
+            }
+            line[column] = c;
+        }
+    }
+
+    lines
+        .iter()
+        .map(|line| line.iter().collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+impl GallowsArt {
+    // Rows of the tallest frame
+    pub fn height(&self) -> u16 {
+        self.all_frames()
+            .iter()
+            .map(|frame| frame.lines().count() as u16)
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn all_frames(&self) -> Vec<String> {
+        if !self.frames.is_empty() {
+            return self.frames.clone();
+        }
+
+        let mut frames = vec![self.base.clone()];
+        for part in &self.parts {
+            let next = overlay(frames.last().unwrap_or(&self.base), part);
+            frames.push(next);
+        }
+        frames
+    }
+
+    // The frames are spread evenly over the misses you're allowed, so the last
+    // one only shows once the game is lost
+    pub fn frame_for(&self, misses: u32, max_misses: u32) -> String {
+        let frames = self.all_frames();
+        let last = frames.len().saturating_sub(1) as u32;
+        let index = match max_misses {
+            max if misses >= max => last,
+            // with only a few frames the rounding would get there a miss early
+            max => ((misses * last + max / 2) / max).min(last.saturating_sub(1)),
+        };
+
+        frames.get(index as usize).cloned().unwrap_or_default()
+    }
+}
+
+#[derive(Deserialize)]
+struct GallowsFile {
+    sets: Vec<GallowsArt>,
+}
+
+pub fn load_gallows() -> Vec<GallowsArt> {
+    let sets = std::fs::read_to_string(GALLOWS_PATH)
+        .ok()
+        .and_then(|file| serde_json::from_str::<GallowsFile>(&file).ok())
+        .map(|file| file.sets)
+        .unwrap_or_default();
+
+    let sets: Vec<GallowsArt> = sets
+        .into_iter()
+        .filter(|art| !art.frames.is_empty() || !art.base.is_empty())
+        .collect();
+    if sets.is_empty() {
+        vec![GallowsArt::default()]
+    } else {
+        sets
+    }
+}
+
+pub fn gallows_named(name: &str) -> GallowsArt {
+    let mut sets = load_gallows();
+    let index = sets.iter().position(|art| art.name == name).unwrap_or(0);
+    sets.swap_remove(index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // frame 0 to frame 3, so the frame number is easy to read back
+    fn numbered(frames: usize) -> GallowsArt {
+        GallowsArt {
+            name: "test".to_string(),
+            frames: (0..frames).map(|frame| frame.to_string()).collect(),
+            base: String::new(),
+            parts: Vec::new(),
+        }
+    }
+
+    fn shown(art: &GallowsArt, max_misses: u32) -> Vec<String> {
+        (0..=max_misses).map(|misses| art.frame_for(misses, max_misses)).collect()
+    }
+
+    #[test]
+    fn the_last_frame_only_shows_once_the_game_is_lost() {
+        // fewer frames than guesses, more of them, and exactly one per guess
+        for (frames, max_misses) in [(4, 9), (7, 9), (10, 9), (20, 9), (2, 3)] {
+            let art = numbered(frames);
+            let last = (frames - 1).to_string();
+            let shown = shown(&art, max_misses);
+            assert_eq!(shown[0], "0");
+            assert_eq!(shown[max_misses as usize], last);
+            assert!(shown[..max_misses as usize].iter().all(|frame| *frame != last));
+        }
+    }
+
+    #[test]
+    fn every_frame_shows_when_there_is_one_per_miss() {
+        let shown = shown(&numbered(10), 9);
+        let expected: Vec<String> = (0..10).map(|frame| frame.to_string()).collect();
+        assert_eq!(shown, expected);
+    }
+
+    #[test]
+    fn parts_are_drawn_over_the_base() {
+        let art = GallowsArt::default();
+        assert_eq!(art.frame_for(0, 9), art.base);
+        // the spaces in the parts leave what's under them alone
+        let lost = art.frame_for(9, 9);
+        assert_eq!(lost.lines().nth(2), Some("|  (x)"));
+        assert_eq!(lost.lines().nth(3), Some("| _/|\\_"));
+        assert_eq!(art.height(), 7);
+    }
+}
@@ -1,14 +1,37 @@
-use crate::games::Game;
-use crate::ui::hangman_ui::render_hangman_game;
+use crate::games::daily::{daily_entry, Daily, HINT, HIT, MISS, SOLVED, WRONG_SOLVE};
+use crate::games::gallows::GallowsArt;
+use crate::games::hot_seat::{HotSeat, SecretEntry};
+use crate::games::letters::LetterRules;
+use crate::games::score::Score;
+use crate::games::timer::{Stopwatch, TimeMode, Timer};
+use crate::games::word_index::WordIndex;
+use crate::games::word_packs::{Difficulty, WordEntry, WordPack, WordSelection};
+use crate::games::{Game, GameOutcome, HangmanRound};
+use crate::profile::Profile;
+use crate::ui::hangman_ui::{keyboard_area, render_hangman_game};
+use crate::ui::keyboard::key_at;
 use rand::seq::SliceRandom;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use rand::thread_rng;
 use ratatui::crossterm::event::KeyCode;
 use ratatui::{Frame, layout::Rect};
-use std::{
-    fs::File,
-    io::{BufReader, prelude::*},
-    path::Path,
-};
+
+// how many wrong guesses the gallows has room for
+pub const MAX_GUESSES: u32 = 10;
+// guessing the whole word wrong costs this many lives
+pub const WRONG_SOLVE_COST: u32 = 2;
+const MAX_SOLVE_LENGTH: usize = 60;
+// evil mode wants some room to dodge, so it prefers lengths with this many words
+const EVIL_MIN_CANDIDATES: usize = 8;
+// how many ticks a freshly drawn part of the gallows lights up
+const GALLOWS_FLASH_TICKS: u8 = 3;
+
+// How a letter on the on-screen keyboard is drawn
+pub enum KeyState {
+    Unused,
+    Correct,
+    Wrong,
+}
 
 pub struct HangmanGame {
     pub guess_input: String,
@@ -18,8 +41,51 @@ pub struct HangmanGame {
     pub has_won: bool,
     pub max_guesses: u32,
     pub current_guess_index: u32,
-    pub previous_words: Vec<String>,
+    // pack name -> words that already came up, kept in the profile between runs
+    pub previous_words: HashMap<String, HashSet<String>>,
+    // packs whose history was reset here, the profile forgets those too
+    pub forgotten_packs: HashSet<String>,
+    // the packs to pick from, loaded once and kept between rounds
+    pub words: WordIndex,
     pub all_words_exhausted: bool,
+    pub selection: WordSelection,
+    // where the current word came from
+    pub pack_name: String,
+    pub difficulty: Option<Difficulty>,
+    pub hint: Option<String>,
+    pub definition: Option<String>,
+    pub attribution: Option<String>,
+    pub hint_shown: bool,
+    pub rules: LetterRules,
+    // Some while typing a guess for the whole word
+    pub solve_input: Option<String>,
+    pub wrong_solves: u32,
+    pub solved: bool,
+    // letters that were still hidden when the whole word was guessed
+    pub solve_bonus: u32,
+    // evil mode only commits to a word at the end, until then word_to_guess is
+    // just one of the candidates that still fit everything revealed so far
+    pub evil: bool,
+    pub candidates: Vec<String>,
+    // two players taking turns, the word comes from the other player
+    pub hot_seat: Option<HotSeat>,
+    pub timer: Option<Timer>,
+    // time spent on this word, for the speed bonus
+    pub stopwatch: Stopwatch,
+    // the top score of the pack, to tell when it gets beaten
+    pub best_points: u32,
+    // the word of the day, the same for everyone and only once
+    pub daily: Option<Daily>,
+    // a tournament round, the hub decides what comes after it
+    pub tournament: bool,
+    // lives lost to the per-guess countdown
+    pub timeouts: u32,
+    pub art: GallowsArt,
+    // the drawing catches up with the misses one at a time, flashing each new part
+    pub gallows_shown: u32,
+    pub gallows_flash: u8,
+    // the whole terminal, to find the keyboard key under a mouse click
+    screen: Rect,
 }
 
 impl HangmanGame {
@@ -30,27 +96,63 @@ impl HangmanGame {
             used_characters: vec![],
             game_finished: false,
             has_won: false,
-            max_guesses: 10,
+            max_guesses: MAX_GUESSES,
             current_guess_index: 0,
-            previous_words: Vec::new(),
+            previous_words: HashMap::new(),
+            forgotten_packs: HashSet::new(),
+            words: WordIndex::default(),
             all_words_exhausted: false,
+            selection: WordSelection::default(),
+            pack_name: String::new(),
+            difficulty: None,
+            hint: None,
+            definition: None,
+            attribution: None,
+            hint_shown: false,
+            rules: LetterRules::default(),
+            solve_input: None,
+            wrong_solves: 0,
+            solved: false,
+            solve_bonus: 0,
+            evil: false,
+            candidates: Vec::new(),
+            hot_seat: None,
+            timer: None,
+            stopwatch: Stopwatch::default(),
+            best_points: 0,
+            daily: None,
+            tournament: false,
+            timeouts: 0,
+            art: GallowsArt::default(),
+            gallows_shown: 0,
+            gallows_flash: 0,
+            screen: Rect::default(),
         }
     }
 
-    pub fn start_new_game(&mut self) {
-        if self.all_words_exhausted {
-            self.previous_words.clear();
-            self.all_words_exhausted = false;
+    pub fn with_selection(selection: WordSelection) -> Self {
+        Self {
+            selection,
+            ..Self::new()
         }
+    }
 
-        if !self.word_to_guess.is_empty() {
-            self.previous_words.push(self.word_to_guess.clone());
+    pub fn start_new_game(&mut self) {
+        self.reset_round();
+
+        if let Some(hot_seat) = &mut self.hot_seat {
+            // the word gets typed in by the other player instead
+            hot_seat.entry = Some(SecretEntry::default());
+            return;
         }
 
-        let previous_words = self.previous_words.clone();
-        *self = Self::new();
-        self.previous_words = previous_words;
-        self.word_to_guess = self.get_word_to_guess();
+        if self.daily.is_some() {
+            self.pick_daily_word();
+        } else if self.evil {
+            self.pick_candidates();
+        } else {
+            self.pick_word_to_guess();
+        }
 
         if self.word_to_guess.is_empty() {
             self.all_words_exhausted = true;
@@ -59,48 +161,510 @@ impl HangmanGame {
         }
     }
 
+    // A clean board that keeps the settings, the history and the loaded packs,
+    // without a word yet
+    fn reset_round(&mut self) {
+        let previous_words = std::mem::take(&mut self.previous_words);
+        let forgotten_packs = std::mem::take(&mut self.forgotten_packs);
+        let art = std::mem::take(&mut self.art);
+        let words = std::mem::take(&mut self.words);
+        *self = Self {
+            // before the hot seat and the timer move on, they decide what counts
+            best_points: self.best_points.max(self.points()),
+            rules: self.rules,
+            evil: self.evil,
+            hot_seat: self.hot_seat.take(),
+            timer: self.timer.map(Timer::next_round),
+            daily: self.daily.take(),
+            tournament: self.tournament,
+            screen: self.screen,
+            ..Self::with_selection(self.selection.clone())
+        };
+        self.previous_words = previous_words;
+        self.forgotten_packs = forgotten_packs;
+        self.art = art;
+        self.words = words;
+    }
+
+    // Forgets which words came up in the packs you're playing, so they can all
+    // come up again
+    pub fn reset_words(&mut self) {
+        self.words.refresh(self.selection.phrases, self.rules);
+        for pack in &self.words.packs {
+            if self.selection.pack.as_ref().is_none_or(|name| *name == pack.name) {
+                self.previous_words.remove(&pack.name);
+                self.forgotten_packs.insert(pack.name.clone());
+            }
+        }
+        self.start_new_game();
+    }
+
+    fn was_played(&self, pack: &str, word: &str) -> bool {
+        self.previous_words
+            .get(pack)
+            .is_some_and(|words| words.contains(word))
+    }
+
+    // The profile keeps the history sorted, so the file doesn't shuffle on every save
+    pub fn load_history(&mut self, history: &BTreeMap<String, BTreeSet<String>>) {
+        self.previous_words = history
+            .iter()
+            .map(|(pack, words)| (pack.clone(), words.iter().cloned().collect()))
+            .collect();
+    }
+
+    // Starts a round on a word picked by someone else, e.g. to race on the same word
+    pub fn start_with_word(&mut self, word: String) {
+        self.reset_round();
+        self.difficulty = Some(Difficulty::of_word(&word));
+        self.word_to_guess = word;
+    }
+
+    fn start_secret_round(&mut self, word: String, hint: Option<String>) {
+        if let Some(hot_seat) = &mut self.hot_seat {
+            hot_seat.entry = None;
+            self.pack_name = format!(
+                "speler {} → speler {}",
+                hot_seat.setter() + 1,
+                hot_seat.guesser() + 1
+            );
+        }
+
+        self.difficulty = Some(Difficulty::of_word(&word));
+        self.hint = hint;
+        self.word_to_guess = word;
+    }
+
+    // a hint counts as a wrong guess, guessing the whole word wrong as two and
+    // so does running out of time
     pub fn get_bad_guess_amount(&self) -> u32 {
-        self.used_characters
+        (self.used_characters
             .iter()
-            .filter(|c| !self.word_to_guess.contains(**c))
+            .filter(|c| !self.word_contains(**c))
             .count() as u32
+            + self.hint_shown as u32
+            + self.wrong_solves * WRONG_SOLVE_COST
+            + self.timeouts)
+            .min(self.max_guesses)
+    }
+
+    pub fn key_state(&self, key: char) -> KeyState {
+        if !self.used_characters.contains(&key) {
+            KeyState::Unused
+        } else if self.word_contains(key) {
+            KeyState::Correct
+        } else {
+            KeyState::Wrong
+        }
     }
 
-    fn get_lines(filename: impl AsRef<Path>) -> Vec<String> {
-        let file = File::open(filename).expect("bestand bestaat niet");
-        let buf = BufReader::new(file);
-        buf.lines()
-            .map(|l| l.expect("kon die lijn niet krijgen broer"))
+    fn word_contains(&self, key: char) -> bool {
+        self.rules
+            .units(&self.word_to_guess)
+            .iter()
+            .any(|unit| self.rules.key_for_unit(unit) == Some(key))
+    }
+
+    // Letters show once guessed, everything else is always shown
+    pub fn is_revealed(&self, unit: &str) -> bool {
+        self.rules
+            .key_for_unit(unit)
+            .is_none_or(|key| self.used_characters.contains(&key))
+    }
+
+    pub fn hint_available(&self) -> bool {
+        self.hint.is_some() && !self.hint_shown && !self.game_finished
+    }
+
+    fn use_hint(&mut self) {
+        if !self.hint_available() {
+            return;
+        }
+
+        self.hint_shown = true;
+        self.mark_daily(HINT);
+        self.guessed();
+        self.current_guess_index += 1;
+        if self.current_guess_index >= self.max_guesses {
+            self.game_finished = true;
+            self.has_won = false;
+        }
+    }
+
+    // The letters of a word as keys, so "Café-au-lait" and "cafe au lait" match
+    fn solve_keys(&self, word: &str) -> Vec<char> {
+        self.rules
+            .units(word)
+            .iter()
+            .filter_map(|unit| self.rules.key_for_unit(unit))
             .collect()
     }
 
-    fn get_word_to_guess(&self) -> String {
-        let contents = Self::get_lines("data/hangman_words.txt");
+    fn submit_solve(&mut self, guess: &str) {
+        let guess = self.solve_keys(guess);
+        if guess.is_empty() {
+            return;
+        }
+        self.guessed();
+
+        // the right word only counts once it's the last one left
+        if self.evil {
+            let others: Vec<String> = self
+                .candidates
+                .iter()
+                .filter(|word| self.solve_keys(word) != guess)
+                .cloned()
+                .collect();
+            if !others.is_empty() {
+                self.candidates = others;
+                self.word_to_guess = self.candidates[0].clone();
+            }
+        }
+
+        if guess == self.solve_keys(&self.word_to_guess) {
+            let hidden: Vec<char> = self
+                .rules
+                .units(&self.word_to_guess)
+                .iter()
+                .filter(|unit| !self.is_revealed(unit))
+                .filter_map(|unit| self.rules.key_for_unit(unit))
+                .collect();
+            self.solve_bonus = hidden.len() as u32;
+            self.solved = true;
+            self.mark_daily(SOLVED);
+
+            for key in hidden {
+                if !self.used_characters.contains(&key) {
+                    self.used_characters.push(key);
+                }
+            }
+            self.has_won = true;
+            self.game_finished = true;
+        } else {
+            self.mark_daily(WRONG_SOLVE);
+            self.wrong_solves += 1;
+            self.current_guess_index =
+                (self.current_guess_index + WRONG_SOLVE_COST).min(self.max_guesses);
+            if self.current_guess_index >= self.max_guesses {
+                self.game_finished = true;
+                self.has_won = false;
+            }
+        }
+    }
+
+    fn handle_solve_input(&mut self, key: KeyCode) {
+        let Some(input) = &mut self.solve_input else {
+            return;
+        };
+
+        match key {
+            KeyCode::Enter => {
+                let guess = std::mem::take(input);
+                self.solve_input = None;
+                self.submit_solve(&guess);
+            }
+            // backspace on an empty box closes it again
+            KeyCode::Backspace if input.is_empty() => self.solve_input = None,
+            KeyCode::Backspace => {
+                input.pop();
+            }
+            KeyCode::Char(c) if input.chars().count() < MAX_SOLVE_LENGTH => input.push(c),
+            _ => {}
+        }
+    }
 
-        let available_words: Vec<String> = contents
+    // Keeps every word of one length around, so there is plenty to dodge with
+    fn pick_candidates(&mut self) {
+        let rules = self.rules;
+        self.words.refresh(false, rules);
+
+        // only plain words, so every candidate has the same shape
+        let mut by_length: BTreeMap<usize, Vec<String>> = BTreeMap::new();
+        for length in self.words.lengths() {
+            let words: Vec<String> = self
+                .words
+                .of_length(length)
+                .filter(|(pack, entry)| self.selection.allows(pack, entry))
+                .filter(|(pack, entry)| !self.was_played(&pack.name, entry.word()))
+                .map(|(_, entry)| entry.word().to_string())
+                .collect();
+            if !words.is_empty() {
+                by_length.insert(length, words);
+            }
+        }
+
+        let roomy: Vec<usize> = by_length
             .iter()
-            .filter(|word| !self.previous_words.contains(&word.to_string()))
-            .cloned()
+            .filter(|(_, words)| words.len() >= EVIL_MIN_CANDIDATES)
+            .map(|(length, _)| *length)
+            .collect();
+        let length = roomy.choose(&mut thread_rng()).copied().or_else(|| {
+            by_length
+                .iter()
+                .max_by_key(|(_, words)| words.len())
+                .map(|(length, _)| *length)
+        });
+
+        let Some(mut candidates) = length.and_then(|length| by_length.remove(&length)) else {
+            return;
+        };
+        candidates.sort();
+        candidates.dedup();
+
+        self.word_to_guess = candidates[0].clone();
+        self.pack_name = format!("😈 gemeen • {} letters", rules.units(&self.word_to_guess).len());
+        self.candidates = candidates;
+    }
+
+    // Splits the candidates by where the guessed letter would show up and keeps
+    // the biggest family, preferring the one where it doesn't show up at all
+    fn dodge(&mut self, key: char) {
+        let rules = self.rules;
+        let mut families: BTreeMap<Vec<usize>, Vec<String>> = BTreeMap::new();
+
+        for word in self.candidates.drain(..) {
+            let positions = rules
+                .units(&word)
+                .iter()
+                .enumerate()
+                .filter(|(_, unit)| rules.key_for_unit(unit) == Some(key))
+                .map(|(index, _)| index)
+                .collect();
+            families.entry(positions).or_default().push(word);
+        }
+
+        if let Some((_, family)) = families
+            .into_iter()
+            .max_by_key(|(positions, words)| (words.len(), positions.is_empty()))
+        {
+            self.word_to_guess = family[0].clone();
+            self.candidates = family;
+        }
+    }
+
+    // Bookkeeping for when a guess ended the round
+    fn after_guess(&mut self) {
+        if !self.game_finished {
+            return;
+        }
+
+        self.commit_word();
+        if let Some(hot_seat) = &mut self.hot_seat {
+            hot_seat.record_round(self.has_won);
+        }
+
+        if let Some(timer) = &mut self.timer {
+            if self.has_won {
+                timer.words_solved += 1;
+            }
+        }
+
+        // a blitz goes straight on to the next word until the clock runs out,
+        // and running out of words ends it just the same
+        if self.timer.is_some_and(|timer| timer.mode == TimeMode::Blitz && !timer.run_over) {
+            if self.has_words_left() {
+                self.start_new_game();
+            } else if let Some(timer) = &mut self.timer {
+                timer.run_over = true;
+            }
+        }
+    }
+
+    // Whether the next pick would still find a word you haven't had
+    fn has_words_left(&self) -> bool {
+        let fresh = |(pack, entry): (&WordPack, &WordEntry)| {
+            self.selection.allows(pack, entry) && !self.was_played(&pack.name, entry.word())
+        };
+        if self.evil {
+            self.words.lengths().any(|length| self.words.of_length(length).any(fresh))
+        } else {
+            self.words.entries().any(fresh)
+        }
+    }
+
+    // A real guess, so the countdown starts over
+    fn guessed(&mut self) {
+        if let Some(timer) = &mut self.timer {
+            timer.restart_countdown();
+        }
+    }
+
+    fn time_out(&mut self) {
+        let Some(timer) = &mut self.timer else {
+            return;
+        };
+
+        match timer.mode {
+            TimeMode::PerGuess => {
+                timer.restart_countdown();
+                self.timeouts += 1;
+                self.current_guess_index += 1;
+                if self.current_guess_index >= self.max_guesses {
+                    self.game_finished = true;
+                    self.has_won = false;
+                }
+            }
+            TimeMode::Blitz => {
+                timer.run_over = true;
+                self.game_finished = true;
+                self.has_won = false;
+            }
+            TimeMode::Off => {}
+        }
+
+        self.after_guess();
+    }
+
+    // Once the round is over the evil game finally settles on a word
+    fn commit_word(&mut self) {
+        if !self.evil {
+            return;
+        }
+
+        if !self.has_won {
+            if let Some(word) = self.candidates.choose(&mut thread_rng()) {
+                self.word_to_guess = word.clone();
+            }
+        }
+        self.candidates.clear();
+
+        // from here on it's a word like any other pick, so it goes in the history
+        let pack = self
+            .words
+            .entries()
+            .find(|(pack, entry)| {
+                self.selection.allows(pack, entry) && entry.word() == self.word_to_guess
+            })
+            .map(|(pack, _)| pack.name.clone());
+        if let Some(pack) = pack {
+            self.previous_words
+                .entry(pack)
+                .or_default()
+                .insert(self.word_to_guess.clone());
+        }
+    }
+
+    fn use_entry(&mut self, pack_name: String, difficulty: Difficulty, entry: &WordEntry) {
+        self.word_to_guess = entry.word().to_string();
+        self.pack_name = pack_name;
+        self.difficulty = Some(difficulty);
+        self.hint = entry.hint().map(str::to_string);
+        self.definition = entry.definition().map(str::to_string);
+        self.attribution = entry.attribution().map(str::to_string);
+    }
+
+    fn pick_word_to_guess(&mut self) {
+        self.words.refresh(self.selection.phrases, self.rules);
+
+        let available_words: Vec<_> = self
+            .words
+            .entries()
+            .filter(|(pack, entry)| self.selection.allows(pack, entry))
+            .filter(|(pack, entry)| !self.was_played(&pack.name, entry.word()))
             .collect();
 
-        if available_words.is_empty() {
-            return String::new();
+        let picked = available_words
+            .choose(&mut thread_rng())
+            .map(|(pack, entry)| (pack.name.clone(), pack.difficulty_of(entry), (*entry).clone()));
+
+        if let Some((pack_name, difficulty, entry)) = picked {
+            self.previous_words
+                .entry(pack_name.clone())
+                .or_default()
+                .insert(entry.word().to_string());
+            self.use_entry(pack_name, difficulty, &entry);
+        }
+    }
+
+    // The word of the day stays out of the history, it's not the random pick
+    fn pick_daily_word(&mut self) {
+        self.words.refresh(self.selection.phrases, self.rules);
+        let Some(daily) = &self.daily else {
+            return;
+        };
+
+        let entries = self
+            .words
+            .entries()
+            .filter(|(pack, entry)| self.selection.allows(pack, entry));
+        let picked = daily_entry(entries, &daily.day(), &daily.pack)
+            .map(|(pack, entry)| (pack.name.clone(), pack.difficulty_of(entry), entry.clone()));
+
+        if let Some((pack_name, difficulty, entry)) = picked {
+            self.use_entry(pack_name, difficulty, &entry);
+        }
+    }
+
+    fn mark_daily(&mut self, mark: &str) {
+        if let Some(daily) = &mut self.daily {
+            daily.marks.push_str(mark);
+        }
+    }
+
+    // Two players keep their own tally and a blitz counts words, so only the
+    // usual rounds get points
+    pub fn is_scored(&self) -> bool {
+        let blitz = self.timer.is_some_and(|timer| timer.mode == TimeMode::Blitz);
+        self.hot_seat.is_none() && !blitz
+    }
+
+    // What the word is worth if it gets guessed right now
+    pub fn score(&self) -> Score {
+        let keys = self.solve_keys(&self.word_to_guess);
+        let lives_left = self.max_guesses.saturating_sub(self.current_guess_index);
+        Score::new(
+            &keys,
+            self.hint_shown,
+            lives_left,
+            self.max_guesses,
+            self.stopwatch.elapsed,
+        )
+    }
+
+    // The points this round ended with, nothing until it's won
+    pub fn points(&self) -> u32 {
+        if self.has_won && self.is_scored() {
+            self.score().total()
+        } else {
+            0
         }
+    }
 
-        let mut rng = thread_rng();
+    pub fn is_new_record(&self) -> bool {
+        self.points() > self.best_points
+    }
 
-        available_words
-            .choose(&mut rng)
-            .cloned()
-            .unwrap_or_else(|| "ERROR_WORD".to_string())
+    pub fn daily_summary(&self) -> Option<String> {
+        self.daily
+            .as_ref()
+            .map(|daily| daily.summary(self.has_won, self.get_bad_guess_amount()))
     }
 }
 
 impl Game for HangmanGame {
     fn handle_input(&mut self, key: KeyCode) {
+        if let Some(entry) = self.hot_seat.as_mut().and_then(|h| h.entry.as_mut()) {
+            if let Some((word, hint)) = entry.handle_input(key) {
+                self.start_secret_round(word, hint);
+            }
+            return;
+        }
+
         if self.all_words_exhausted {
-            if let KeyCode::Char('r') | KeyCode::Char('R') | KeyCode::Enter = key {
-                self.start_new_game();
+            if let KeyCode::Char('w') | KeyCode::Char('W') = key {
+                self.reset_words();
+            }
+            return;
+        }
+
+        // the word of the day is done for today, all that's left is sharing it
+        if let (true, Some(summary)) = (self.game_finished, self.daily_summary()) {
+            if let Some(daily) = &mut self.daily {
+                match key {
+                    KeyCode::Char('c') | KeyCode::Char('C') => daily.copy(&summary),
+                    KeyCode::Char('s') | KeyCode::Char('S') => daily.save(&summary),
+                    _ => {}
+                }
             }
             return;
         }
@@ -112,26 +676,42 @@ impl Game for HangmanGame {
             return;
         }
 
+        if self.solve_input.is_some() {
+            self.handle_solve_input(key);
+            self.after_guess();
+            return;
+        }
+
         match key {
-            KeyCode::Char(c) if c.is_ascii_alphabetic() => {
-                let c = c.to_ascii_lowercase();
+            KeyCode::Char('?') => self.use_hint(),
+            KeyCode::Char('/') | KeyCode::Enter => self.solve_input = Some(String::new()),
+            KeyCode::Char(c) => {
+                let Some(c) = self.rules.key_for_input(c) else {
+                    return;
+                };
                 if !self.used_characters.contains(&c) {
                     self.used_characters.push(c);
+                    self.guessed();
+                    if self.evil {
+                        self.dodge(c);
+                    }
 
-                    if self.word_to_guess.contains(c) {
+                    if self.word_contains(c) {
+                        self.mark_daily(HIT);
                         self.guess_input.push(c);
 
                         let all_guessed = self
-                            .word_to_guess
-                            .chars()
-                            .filter(|ch| *ch != ' ')
-                            .all(|ch| self.used_characters.contains(&ch));
+                            .rules
+                            .units(&self.word_to_guess)
+                            .iter()
+                            .all(|unit| self.is_revealed(unit));
 
                         if all_guessed {
                             self.has_won = true;
                             self.game_finished = true;
                         }
                     } else {
+                        self.mark_daily(MISS);
                         self.current_guess_index += 1;
                         if self.current_guess_index >= self.max_guesses {
                             self.game_finished = true;
@@ -142,6 +722,55 @@ impl Game for HangmanGame {
             }
             _ => {}
         }
+
+        self.after_guess();
+    }
+
+    fn resize(&mut self, width: u16, height: u16) {
+        self.screen = Rect::new(0, 0, width, height);
+    }
+
+    // clicking a key on the on-screen keyboard guesses that letter
+    fn handle_click(&mut self, column: u16, row: u16) {
+        let typing = self.solve_input.is_some()
+            || self.hot_seat.as_ref().is_some_and(|hot_seat| hot_seat.entry.is_some());
+        if typing || self.game_finished {
+            return;
+        }
+
+        if let Some(letter) = key_at(keyboard_area(self, self.screen), column, row) {
+            self.handle_input(KeyCode::Char(letter));
+        }
+    }
+
+    fn take_clipboard(&mut self) -> Option<String> {
+        self.daily.as_mut()?.clipboard.take()
+    }
+
+    // also when every word is used up, which isn't a round anyone played
+    fn is_over(&self) -> bool {
+        self.game_finished
+    }
+
+    fn save_progress(&self, profile: &mut Profile) {
+        if let (true, Some(daily)) = (self.game_finished, &self.daily) {
+            if !profile.daily.finished_on(&daily.pack, &daily.day()) {
+                let summary = daily.summary(self.has_won, self.get_bad_guess_amount());
+                profile
+                    .daily
+                    .record(daily.date, &daily.pack, self.has_won, summary);
+            }
+        }
+
+        // merged rather than copied over, so a game that never loaded the
+        // history, or another session's game, can't wipe it
+        for pack in &self.forgotten_packs {
+            profile.hangman_history.remove(pack);
+        }
+        for (pack, words) in &self.previous_words {
+            let history = profile.hangman_history.entry(pack.clone()).or_default();
+            history.extend(words.iter().cloned());
+        }
     }
 
     fn render(&self, frame: &mut Frame, area: Rect) {
@@ -149,6 +778,191 @@ impl Game for HangmanGame {
     }
 
     fn restart(&mut self) {
+        // no second try at the word of the day, not even halfway through
+        if self.daily.is_some() && !self.word_to_guess.is_empty() {
+            return;
+        }
         self.start_new_game();
     }
+
+    fn tick(&mut self) {
+        let misses = self.get_bad_guess_amount();
+        if self.gallows_flash > 0 {
+            self.gallows_flash -= 1;
+        } else if self.gallows_shown < misses {
+            self.gallows_shown += 1;
+            self.gallows_flash = GALLOWS_FLASH_TICKS;
+        }
+
+        let waiting = self.game_finished
+            || self.hot_seat.as_ref().is_some_and(|hot_seat| hot_seat.entry.is_some());
+        if !waiting {
+            self.stopwatch.advance();
+        }
+
+        let Some(timer) = &mut self.timer else {
+            return;
+        };
+
+        if !waiting && timer.advance() {
+            self.time_out();
+        }
+    }
+
+    // the border, two rows of boxes and the keyboard above the whole drawing
+    fn min_size(&self) -> (u16, u16) {
+        (36, 13 + self.art.height())
+    }
+
+    fn outcome(&self) -> Option<GameOutcome> {
+        if !self.game_finished || self.all_words_exhausted {
+            return None;
+        }
+
+        // a blitz is about how many words you got before the clock ran out
+        if let Some(timer) = self.timer.filter(|timer| timer.run_over) {
+            return Some(GameOutcome {
+                won: timer.words_solved > 0,
+                score: timer.words_solved * 5,
+                hangman: None,
+            });
+        }
+
+        // every guess you had left is worth a point, and so is every letter
+        // you didn't need because you guessed the whole word. Tournaments add
+        // this up next to snake and the adventure, which score in the tens, so
+        // the leaderboard points (hundreds for one word) only go in the round.
+        Some(GameOutcome {
+            won: self.has_won,
+            score: if self.has_won {
+                self.max_guesses - self.current_guess_index + self.solve_bonus
+            } else {
+                0
+            },
+            // words typed in by a friend don't say much about how you're doing
+            hangman: self.hot_seat.is_none().then(|| HangmanRound {
+                solve_attempts: self.wrong_solves + self.solved as u32,
+                solved: self.solved,
+                word: self.word_to_guess.clone(),
+                pack: self.selection.name(),
+                points: self.points(),
+                wrong_guesses: self.get_bad_guess_amount(),
+                missed_letters: self
+                    .used_characters
+                    .iter()
+                    .copied()
+                    .filter(|c| !self.word_contains(*c))
+                    .collect(),
+            }),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn evil_game(words: &[&str]) -> HangmanGame {
+        let mut game = HangmanGame::new();
+        game.evil = true;
+        game.candidates = words.iter().map(|word| word.to_string()).collect();
+        game.word_to_guess = game.candidates[0].clone();
+        game
+    }
+
+    fn game_with_word(word: &str, time: TimeMode) -> HangmanGame {
+        let mut game = HangmanGame::new();
+        game.timer = (time != TimeMode::Off).then(|| Timer::new(time));
+        game.start_with_word(word.to_string());
+        game
+    }
+
+    fn guess(game: &mut HangmanGame, letters: &str) {
+        for letter in letters.chars() {
+            game.handle_input(KeyCode::Char(letter));
+        }
+    }
+
+    #[test]
+    fn a_win_scores_with_and_without_the_timer() {
+        for time in [TimeMode::Off, TimeMode::PerGuess] {
+            let mut game = game_with_word("kat", time);
+            guess(&mut game, "ekat");
+            assert!(game.has_won);
+            assert_eq!(game.points(), game.score().total());
+            assert!(game.points() > 0);
+
+            // the tournament score is the guesses that were left
+            let outcome = game.outcome().unwrap();
+            assert!(outcome.won);
+            assert_eq!(outcome.score, MAX_GUESSES - 1);
+            assert_eq!(outcome.hangman.unwrap().points, game.points());
+        }
+    }
+
+    #[test]
+    fn a_loss_scores_nothing() {
+        for time in [TimeMode::Off, TimeMode::PerGuess] {
+            let mut game = game_with_word("kat", time);
+            guess(&mut game, "eoinrdslgv");
+            assert!(game.game_finished && !game.has_won);
+            assert_eq!(game.points(), 0);
+
+            let outcome = game.outcome().unwrap();
+            assert!(!outcome.won);
+            assert_eq!(outcome.score, 0);
+        }
+    }
+
+    #[test]
+    fn a_blitz_scores_the_words_instead() {
+        // no words loaded, so the blitz is over after this one
+        let mut game = game_with_word("kat", TimeMode::Blitz);
+        guess(&mut game, "kat");
+        assert_eq!(game.points(), 0);
+
+        let outcome = game.outcome().unwrap();
+        assert!(outcome.won);
+        assert_eq!(outcome.score, 5);
+        assert!(outcome.hangman.is_none());
+    }
+
+    #[test]
+    fn dodges_into_the_largest_family() {
+        // kat and kok have no e, bel, pen and tel have it in the middle
+        let mut game = evil_game(&["bel", "kat", "kok", "pen", "tel"]);
+        game.dodge('e');
+        assert_eq!(game.candidates, ["bel", "pen", "tel"]);
+        assert!(game.candidates.contains(&game.word_to_guess));
+    }
+
+    #[test]
+    fn a_tie_goes_to_the_family_without_the_letter() {
+        let mut game = evil_game(&["bel", "kat", "kok", "pen"]);
+        game.dodge('e');
+        assert_eq!(game.candidates, ["kat", "kok"]);
+        assert!(!game.word_to_guess.contains('e'));
+    }
+
+    #[test]
+    fn the_word_never_has_a_letter_that_missed() {
+        let words = ["bal", "bel", "bol", "dak", "kat", "mol", "pen", "rok", "tak", "tel"];
+        let mut game = evil_game(&words);
+        let mut misses = Vec::new();
+        for letter in "eaoltkbmnprd".chars() {
+            if game.game_finished {
+                break;
+            }
+            let before = game.current_guess_index;
+            game.handle_input(KeyCode::Char(letter));
+            if game.current_guess_index > before {
+                misses.push(letter);
+            }
+        }
+
+        assert!(game.game_finished);
+        assert!(!misses.is_empty());
+        assert!(words.contains(&game.word_to_guess.as_str()));
+        assert!(misses.iter().all(|miss| !game.word_to_guess.contains(*miss)));
+    }
 }
@@ -0,0 +1,96 @@
+use ratatui::crossterm::event::KeyCode;
+
+const MAX_SECRET_LENGTH: usize = 40;
+const MAX_HINT_LENGTH: usize = 60;
+
+// What the player thinking of a word has typed so far
+#[derive(Default)]
+pub struct SecretEntry {
+    pub word: String,
+    pub hint: String,
+    pub editing_hint: bool,
+    pub error: Option<&'static str>,
+}
+
+impl SecretEntry {
+    // Some(word, hint) once Enter is pressed on a word that's fine to play with
+    pub fn handle_input(&mut self, key: KeyCode) -> Option<(String, Option<String>)> {
+        let (field, max_length) = if self.editing_hint {
+            (&mut self.hint, MAX_HINT_LENGTH)
+        } else {
+            (&mut self.word, MAX_SECRET_LENGTH)
+        };
+
+        // any edit makes the old complaint moot
+        self.error = None;
+
+        match key {
+            KeyCode::Tab | KeyCode::Down | KeyCode::Up => self.editing_hint = !self.editing_hint,
+            KeyCode::Backspace => {
+                field.pop();
+            }
+            KeyCode::Char(c) if field.chars().count() < max_length => field.push(c),
+            KeyCode::Enter => match validate(&self.word) {
+                Ok(word) => {
+                    let hint = Some(self.hint.trim().to_string()).filter(|hint| !hint.is_empty());
+                    return Some((word, hint));
+                }
+                Err(error) => self.error = Some(error),
+            },
+            _ => {}
+        }
+
+        None
+    }
+}
+
+// Letters, with spaces, hyphens and apostrophes in between, like in the word packs
+fn validate(word: &str) -> Result<String, &'static str> {
+    let word = word.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    if word.chars().filter(|c| c.is_alphabetic()).count() < 2 {
+        return Err("dat is wel heel kort, minstens twee letters");
+    }
+    if !word
+        .chars()
+        .all(|c| c.is_alphabetic() || matches!(c, ' ' | '-' | '\''))
+    {
+        return Err("alleen letters, spaties, streepjes en apostrofs");
+    }
+
+    Ok(word)
+}
+
+// Two players on one keyboard, taking turns thinking of a word
+pub struct HotSeat {
+    pub scores: [u32; 2],
+    pub rounds_played: u32,
+    // Some while the word for the next round is being typed
+    pub entry: Option<SecretEntry>,
+}
+
+impl HotSeat {
+    pub fn new() -> Self {
+        Self {
+            scores: [0, 0],
+            rounds_played: 0,
+            entry: None,
+        }
+    }
+
+    // players are 0 and 1, they swap roles every round
+    pub fn setter(&self) -> usize {
+        self.rounds_played as usize % 2
+    }
+
+    pub fn guesser(&self) -> usize {
+        1 - self.setter()
+    }
+
+    // whoever won the round gets a point
+    pub fn record_round(&mut self, guessed: bool) {
+        let winner = if guessed { self.guesser() } else { self.setter() };
+        self.scores[winner] += 1;
+        self.rounds_played += 1;
+    }
+}
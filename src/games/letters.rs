@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+
+// How hangman compares what you type with the letters in the word
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(default)]
+pub struct LetterRules {
+    // guessing e also reveals é, ë, è and ê
+    pub fold_accents: bool,
+    // ij counts as one letter, guessed with the y like on old typewriters
+    pub ij_as_one_letter: bool,
+}
+
+impl Default for LetterRules {
+    fn default() -> Self {
+        Self {
+            fold_accents: true,
+            ij_as_one_letter: false,
+        }
+    }
+}
+
+fn strip_accent(c: char) -> char {
+    match c {
+        'á' | 'à' | 'ä' | 'â' | 'å' => 'a',
+        'é' | 'è' | 'ë' | 'ê' => 'e',
+        'í' | 'ì' | 'ï' | 'î' => 'i',
+        'ó' | 'ò' | 'ö' | 'ô' => 'o',
+        'ú' | 'ù' | 'ü' | 'û' => 'u',
+        'ç' => 'c',
+        'ñ' => 'n',
+        _ => c,
+    }
+}
+
+impl LetterRules {
+    // The letter a key press counts as, None for keys that aren't letters
+    pub fn key_for_input(&self, c: char) -> Option<char> {
+        if !c.is_alphabetic() {
+            return None;
+        }
+
+        let c = c.to_lowercase().next().unwrap_or(c);
+        Some(if self.fold_accents { strip_accent(c) } else { c })
+    }
+
+    // Splits a word into the pieces that get revealed one at a time, so with
+    // the ij rule "vrijdag" becomes v, r, ij, d, a, g
+    pub fn units<'a>(&self, word: &'a str) -> Vec<&'a str> {
+        let mut units = Vec::new();
+        let mut rest = word;
+
+        while let Some(c) = rest.chars().next() {
+            let len = if self.ij_as_one_letter
+                && rest.get(..2).is_some_and(|pair| pair.eq_ignore_ascii_case("ij"))
+            {
+                2
+            } else {
+                c.len_utf8()
+            };
+            units.push(&rest[..len]);
+            rest = &rest[len..];
+        }
+
+        units
+    }
+
+    // The key that reveals this piece of the word, None if it is always shown
+    // (spaces, punctuation, hyphens and digits)
+    pub fn key_for_unit(&self, unit: &str) -> Option<char> {
+        if unit.len() == 2 && unit.eq_ignore_ascii_case("ij") {
+            return Some('y');
+        }
+
+        unit.chars().next().and_then(|c| self.key_for_input(c))
+    }
+}
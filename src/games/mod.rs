@@ -1,17 +1,30 @@
+pub mod daily;
+pub mod gallows;
 pub mod hangman;
+pub mod hot_seat;
+pub mod reverse_hangman;
+pub mod score;
 pub mod snake;
+pub mod timer;
+#[allow(dead_code)] // not in the menu yet
 pub mod twozerofoureight;
+pub mod word_index;
 pub mod adventure;
+pub mod letters;
+pub mod word_packs;
 
 
 use ratatui::crossterm::event::KeyCode;
 use ratatui::{Frame, layout::Rect};
+use crate::profile::Profile;
+use serde::Deserialize;
 // use twozerofoureight::Game2048;
 
-#[derive(Clone)]
+#[derive(Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum GameType {
     Hangman,
-    // Snake,
+    Snake,
     // Game2048,
     Adventure,
 }
@@ -20,17 +33,59 @@ impl GameType {
     pub fn name(&self) -> &str {
         match self {
             GameType::Hangman => "Galgje",
-            // GameType::Snake => "Snake",
+            GameType::Snake => "Snake",
             // GameType::Game2048 => "2048",
             GameType::Adventure => "Pipis avontuurtje!",
         }
     }
 }
 
-// Game trait remains the same
+// How a finished round went, used to keep score across games
+pub struct GameOutcome {
+    pub won: bool,
+    pub score: u32,
+    // extra details for the hangman stats
+    pub hangman: Option<HangmanRound>,
+}
+
+pub struct HangmanRound {
+    // tries at guessing the whole word at once, including the right one
+    pub solve_attempts: u32,
+    pub solved: bool,
+    pub word: String,
+    // the selected pack, or all of them, for the high scores
+    pub pack: String,
+    pub points: u32,
+    pub wrong_guesses: u32,
+    // letters that weren't in the word
+    pub missed_letters: Vec<char>,
+}
+
 pub trait Game {
     fn handle_input(&mut self, key: KeyCode);
     fn render(&self, frame: &mut Frame, area: Rect);
     fn restart(&mut self);
     fn tick(&mut self) {}
+    // A left click at this spot of the terminal
+    fn handle_click(&mut self, _column: u16, _row: u16) {}
+    // Called whenever the space the game gets to draw in changes
+    fn resize(&mut self, _width: u16, _height: u16) {}
+    // Smallest (width, height) the game can still be played in
+    fn min_size(&self) -> (u16, u16) {
+        (40, 12)
+    }
+    // Copies whatever the game wants to remember between runs into the profile
+    fn save_progress(&self, _profile: &mut Profile) {}
+    // Some once the current round is over
+    fn outcome(&self) -> Option<GameOutcome> {
+        None
+    }
+    // Text for the player's clipboard, handed over once
+    fn take_clipboard(&mut self) -> Option<String> {
+        None
+    }
+    // Nothing left to play until a restart, so Backspace goes back to the hub
+    fn is_over(&self) -> bool {
+        self.outcome().is_some()
+    }
 }
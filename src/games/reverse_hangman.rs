@@ -0,0 +1,231 @@
+use crate::games::gallows::GallowsArt;
+use crate::games::letters::LetterRules;
+use crate::games::word_index::WordIndex;
+use crate::games::word_packs::LETTER_FREQUENCY;
+use crate::games::{Game, GameOutcome};
+use crate::ui::reverse_hangman_ui::render_reverse_hangman;
+use ratatui::crossterm::event::KeyCode;
+use ratatui::{Frame, layout::Rect};
+
+const MIN_LENGTH: usize = 2;
+const MAX_LENGTH: usize = 20;
+const DEFAULT_LENGTH: usize = 6;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Phase {
+    // the player tells how many letters their word has
+    PickLength,
+    // the player marks where the computer's letter goes
+    Marking,
+    Finished,
+}
+
+// You think of a word, the computer guesses letters and you point out where
+// they go. It only knows the words from the word packs, so a word it has
+// never heard of leaves it guessing by how common letters are.
+pub struct ReverseHangmanGame {
+    pub phase: Phase,
+    pub length: usize,
+    // what has been revealed so far, one key per letter
+    pub pattern: Vec<Option<char>>,
+    pub guessed: Vec<char>,
+    pub current_guess: Option<char>,
+    // positions the player marked for the current guess
+    pub marked: Vec<bool>,
+    pub cursor: usize,
+    pub misses: u32,
+    pub max_misses: u32,
+    pub rules: LetterRules,
+    pub art: GallowsArt,
+    // every word from the packs, to look up what still fits
+    words: WordIndex,
+    pub candidates: Vec<Vec<char>>,
+}
+
+impl ReverseHangmanGame {
+    pub fn new(rules: LetterRules) -> Self {
+        Self::with_words(rules, WordIndex::load(false, rules))
+    }
+
+    fn with_words(rules: LetterRules, words: WordIndex) -> Self {
+        Self {
+            phase: Phase::PickLength,
+            length: DEFAULT_LENGTH,
+            pattern: Vec::new(),
+            guessed: Vec::new(),
+            current_guess: None,
+            marked: Vec::new(),
+            cursor: 0,
+            misses: 0,
+            max_misses: 10,
+            rules,
+            art: GallowsArt::default(),
+            words,
+            candidates: Vec::new(),
+        }
+    }
+
+    pub fn words_of_length(&self, length: usize) -> usize {
+        self.words.matching(&vec![None; length], &[]).len()
+    }
+
+    fn start_guessing(&mut self) {
+        self.words.refresh(false, self.rules);
+        self.pattern = vec![None; self.length];
+        self.marked = vec![false; self.length];
+        self.candidates = self.words.matching(&self.pattern, &[]);
+        self.phase = Phase::Marking;
+        self.next_guess();
+    }
+
+    // The letter that shows up in the most candidates, or the most common
+    // letter that is left once the candidates have run out
+    fn next_guess(&mut self) {
+        let unguessed = |c: &char| !self.guessed.contains(c);
+        let rank = |c: char| LETTER_FREQUENCY.find(c).unwrap_or(LETTER_FREQUENCY.len());
+
+        let mut counts: Vec<(char, usize)> = Vec::new();
+        for word in &self.candidates {
+            let mut letters: Vec<char> = word.iter().copied().filter(unguessed).collect();
+            letters.sort_unstable();
+            letters.dedup();
+
+            for letter in letters {
+                match counts.iter_mut().find(|(c, _)| *c == letter) {
+                    Some((_, count)) => *count += 1,
+                    None => counts.push((letter, 1)),
+                }
+            }
+        }
+
+        self.current_guess = counts
+            .into_iter()
+            .max_by_key(|(c, count)| (*count, std::cmp::Reverse(rank(*c))))
+            .map(|(c, _)| c)
+            .or_else(|| LETTER_FREQUENCY.chars().find(unguessed));
+
+        self.marked = vec![false; self.length];
+        self.cursor = self.pattern.iter().position(Option::is_none).unwrap_or(0);
+
+        if self.current_guess.is_none() {
+            // every letter has been tried, the computer gives up
+            self.phase = Phase::Finished;
+        }
+    }
+
+    // Takes the player's answer for the current guess, nothing marked means
+    // the letter isn't in the word
+    fn confirm_marks(&mut self) {
+        let Some(guess) = self.current_guess else {
+            return;
+        };
+
+        self.guessed.push(guess);
+        let hit = self.marked.iter().any(|marked| *marked);
+        for (slot, marked) in self.pattern.iter_mut().zip(&self.marked) {
+            if *marked {
+                *slot = Some(guess);
+            }
+        }
+
+        if !hit {
+            self.misses += 1;
+        }
+
+        self.candidates = self.words.matching(&self.pattern, &self.guessed);
+
+        if self.computer_won() || self.misses >= self.max_misses {
+            self.phase = Phase::Finished;
+        } else {
+            self.next_guess();
+        }
+    }
+
+    pub fn computer_won(&self) -> bool {
+        !self.pattern.is_empty() && self.pattern.iter().all(Option::is_some)
+    }
+
+    fn move_cursor(&mut self, step: isize) {
+        // revealed letters are skipped, there is nothing to mark there
+        let length = self.length as isize;
+        let mut position = self.cursor as isize;
+        for _ in 0..length {
+            position = (position + step).rem_euclid(length);
+            if self.pattern[position as usize].is_none() {
+                self.cursor = position as usize;
+                return;
+            }
+        }
+    }
+
+    fn toggle_mark(&mut self) {
+        if self.pattern.get(self.cursor).is_some_and(Option::is_none) {
+            self.marked[self.cursor] = !self.marked[self.cursor];
+        }
+    }
+}
+
+impl Game for ReverseHangmanGame {
+    fn handle_input(&mut self, key: KeyCode) {
+        match self.phase {
+            Phase::PickLength => match key {
+                KeyCode::Up | KeyCode::Right | KeyCode::Char('k') | KeyCode::Char('l') => {
+                    self.length = (self.length + 1).min(MAX_LENGTH);
+                }
+                KeyCode::Down | KeyCode::Left | KeyCode::Char('j') | KeyCode::Char('h') => {
+                    self.length = self.length.saturating_sub(1).max(MIN_LENGTH);
+                }
+                KeyCode::Enter => self.start_guessing(),
+                _ => {}
+            },
+            Phase::Marking => match key {
+                KeyCode::Right | KeyCode::Char('l') => self.move_cursor(1),
+                KeyCode::Left | KeyCode::Char('h') => self.move_cursor(-1),
+                KeyCode::Char(' ') => self.toggle_mark(),
+                // a quick way to say the letter isn't there
+                KeyCode::Char('n') => {
+                    self.marked = vec![false; self.length];
+                    self.confirm_marks();
+                }
+                KeyCode::Enter => self.confirm_marks(),
+                _ => {}
+            },
+            Phase::Finished => {
+                if let KeyCode::Char('r') | KeyCode::Char('R') | KeyCode::Enter = key {
+                    self.restart();
+                }
+            }
+        }
+    }
+
+    fn render(&self, frame: &mut Frame, area: Rect) {
+        render_reverse_hangman(self, frame, area);
+    }
+
+    fn restart(&mut self) {
+        let words = std::mem::take(&mut self.words);
+        *self = Self {
+            length: self.length,
+            art: std::mem::take(&mut self.art),
+            ..Self::with_words(self.rules, words)
+        };
+    }
+
+    fn min_size(&self) -> (u16, u16) {
+        (40, 18)
+    }
+
+    fn outcome(&self) -> Option<GameOutcome> {
+        if self.phase != Phase::Finished {
+            return None;
+        }
+
+        // you win when the computer doesn't figure it out, and every miss it
+        // made is a point for you
+        Some(GameOutcome {
+            won: !self.computer_won(),
+            score: self.misses,
+            hangman: None,
+        })
+    }
+}
@@ -0,0 +1,103 @@
+use crate::games::word_packs::LETTER_FREQUENCY;
+use std::time::Duration;
+
+// every letter in the word is worth this much
+const LETTER_POINTS: u32 = 10;
+// and a rare one up to this much on top
+const MAX_RARITY_POINTS: u32 = 20;
+// the hint costs this part of the base score, on top of the guess it takes
+const HINT_PENALTY_PERCENT: u32 = 25;
+// guessed within FAST_ROUND gets the whole speed bonus, after SLOW_ROUND none of it
+const FAST_ROUND: Duration = Duration::from_secs(20);
+const SLOW_ROUND: Duration = Duration::from_secs(120);
+const MAX_SPEED_BONUS: f64 = 0.5;
+
+// Letters far down the frequency list are hard to think of, so they're worth
+// more. Anything that isn't on it at all, like é without the accent rule,
+// counts as the rarest.
+pub fn letter_points(key: char) -> u32 {
+    let last = LETTER_FREQUENCY.len() as u32 - 1;
+    let rarity = LETTER_FREQUENCY
+        .chars()
+        .position(|letter| letter == key)
+        .map_or(MAX_RARITY_POINTS, |rank| rank as u32 * MAX_RARITY_POINTS / last);
+    LETTER_POINTS + rarity
+}
+
+// What a hangman round is worth, kept in pieces so the end popup can show
+// where the points came from
+#[derive(Clone, Copy)]
+pub struct Score {
+    // from the length of the word and how rare its letters are
+    pub base: u32,
+    pub hint_penalty: u32,
+    // ×1 with no guesses left, up to ×2 without losing any
+    pub lives: f64,
+    // up to ×1.5 for a quick round
+    pub speed: f64,
+}
+
+impl Score {
+    pub fn new(
+        keys: &[char],
+        hint_shown: bool,
+        lives_left: u32,
+        max_lives: u32,
+        time: Duration,
+    ) -> Self {
+        let base: u32 = keys.iter().map(|key| letter_points(*key)).sum();
+
+        Self {
+            base,
+            hint_penalty: if hint_shown { base * HINT_PENALTY_PERCENT / 100 } else { 0 },
+            lives: 1.0 + lives_left as f64 / max_lives.max(1) as f64,
+            speed: speed_multiplier(time),
+        }
+    }
+
+    pub fn total(&self) -> u32 {
+        let points = self.base.saturating_sub(self.hint_penalty) as f64;
+        (points * self.lives * self.speed).round() as u32
+    }
+}
+
+// Goes down evenly from the whole bonus to nothing between the two round times
+fn speed_multiplier(time: Duration) -> f64 {
+    let slow = SLOW_ROUND.as_secs_f64();
+    let fast = FAST_ROUND.as_secs_f64();
+    let quickness = ((slow - time.as_secs_f64()) / (slow - fast)).clamp(0.0, 1.0);
+    1.0 + MAX_SPEED_BONUS * quickness
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rare_letters_are_worth_more() {
+        assert_eq!(letter_points('e'), LETTER_POINTS);
+        assert_eq!(letter_points('q'), LETTER_POINTS + MAX_RARITY_POINTS);
+        // not on the list at all counts as the rarest
+        assert_eq!(letter_points('é'), LETTER_POINTS + MAX_RARITY_POINTS);
+        assert!(letter_points('k') > letter_points('a'));
+    }
+
+    #[test]
+    fn a_quick_round_gets_the_whole_speed_bonus() {
+        assert_eq!(speed_multiplier(Duration::ZERO), 1.0 + MAX_SPEED_BONUS);
+        assert_eq!(speed_multiplier(FAST_ROUND), 1.0 + MAX_SPEED_BONUS);
+        assert_eq!(speed_multiplier(SLOW_ROUND), 1.0);
+        assert_eq!(speed_multiplier(SLOW_ROUND * 2), 1.0);
+    }
+
+    #[test]
+    fn adds_up_the_pieces() {
+        let keys = ['e', 'e'];
+        // 20 points, twice for not losing a life and half again for being quick
+        assert_eq!(Score::new(&keys, false, 9, 9, Duration::ZERO).total(), 60);
+        // no lives left and slow is just the letters
+        assert_eq!(Score::new(&keys, false, 0, 9, SLOW_ROUND).total(), 20);
+        // the hint takes a quarter off the letters
+        assert_eq!(Score::new(&keys, true, 0, 9, SLOW_ROUND).total(), 15);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::games::Game;
+use crate::games::{Game, GameOutcome};
 use crate::ui::snake_ui;
 use ratatui::crossterm::event::KeyCode;
 use ratatui::{Frame, layout::Rect};
@@ -11,7 +11,16 @@ pub enum Direction {
     Right,
 }
 
-#[derive(Clone, Copy)]
+// The snake lives in a walled field, leaving it is fatal. The field shrinks to
+// fit small terminals, but never below the minimum
+pub const FIELD_WIDTH: u16 = 50;
+pub const FIELD_HEIGHT: u16 = 20;
+const MIN_FIELD_WIDTH: u16 = 16;
+const MIN_FIELD_HEIGHT: u16 = 12;
+// outer block and field border, on both sides
+const FIELD_MARGIN: u16 = 4;
+
+#[derive(Clone, Copy, PartialEq)]
 pub struct Position {
     pub x: u16,
     pub y: u16,
@@ -21,7 +30,13 @@ pub struct SnakeGame {
     pub snake: Vec<Position>,
     direction: Direction,
     pub food: Position,
-    area: Option<Rect>,
+    pub food_eaten: u32,
+    pub game_over: bool,
+    pub field_width: u16,
+    pub field_height: u16,
+    // the field size that fits the terminal, used from the next round on if
+    // the current snake would not fit in it
+    fitting_field: (u16, u16),
 }
 
 impl SnakeGame {
@@ -37,7 +52,11 @@ impl SnakeGame {
             snake,
             direction: Direction::Right,
             food,
-            area: None,
+            food_eaten: 0,
+            game_over: false,
+            field_width: FIELD_WIDTH,
+            field_height: FIELD_HEIGHT,
+            fitting_field: (FIELD_WIDTH, FIELD_HEIGHT),
         }
     }
 
@@ -49,42 +68,48 @@ impl SnakeGame {
         ];
         self.direction = Direction::Right;
         self.food = Position { x: 10, y: 10 };
+        self.food_eaten = 0;
+        self.game_over = false;
+        (self.field_width, self.field_height) = self.fitting_field;
+    }
+
+    fn fits_in(&self, (width, height): (u16, u16)) -> bool {
+        self.snake
+            .iter()
+            .chain(std::iter::once(&self.food))
+            .all(|p| p.x < width && p.y < height)
     }
 
-    pub fn die() {
-        // stop the polling
-        // display end screen like hangman
-        // add option to restart
+    pub fn die(&mut self) {
+        self.game_over = true;
     }
 
     fn update(&mut self) {
-        let mut new_head = self.snake[0].clone();
+        if self.game_over {
+            return;
+        }
 
-        match self.direction {
-            Direction::Up => {
-                if new_head.y > 0 {
-                    new_head.y -= 1;
-                }
-            }
-            Direction::Down => {
-                new_head.y += 1;
-            }
-            Direction::Left => {
-                if new_head.x > 0 {
-                    new_head.x -= 1;
-                }
-            }
-            Direction::Right => {
-                new_head.x += 1;
-            }
+        let head = self.snake[0];
+        let new_head = match self.direction {
+            Direction::Up if head.y > 0 => Position { y: head.y - 1, ..head },
+            Direction::Down if head.y + 1 < self.field_height => Position { y: head.y + 1, ..head },
+            Direction::Left if head.x > 0 => Position { x: head.x - 1, ..head },
+            Direction::Right if head.x + 1 < self.field_width => Position { x: head.x + 1, ..head },
+            // into the wall
+            _ => return self.die(),
+        };
+
+        // als de new head op de slang zelf komt, dan is de snake dood
+        if self.snake.contains(&new_head) {
+            return self.die();
         }
-        // als de new head hetzelfde is als de laatste, dan is de snake dood
 
-        if new_head.x == self.food.x && new_head.y == self.food.y {
+        if new_head == self.food {
             self.snake.insert(0, new_head);
+            self.food_eaten += 1;
             self.food = Position {
-                x: (new_head.x + 5) % 50,
-                y: (new_head.y + 3) % 20,
+                x: (new_head.x + 5) % self.field_width,
+                y: (new_head.y + 3) % self.field_height,
             }; // basic respawn logic
         } else {
             self.snake.insert(0, new_head);
@@ -113,6 +138,13 @@ impl Game for SnakeGame {
     }
 
     fn handle_input(&mut self, key: KeyCode) {
+        if self.game_over {
+            if let KeyCode::Char('r') | KeyCode::Char('R') | KeyCode::Enter = key {
+                self.start_new_game();
+            }
+            return;
+        }
+
         match key {
             KeyCode::Up => self.change_direction(Direction::Up),
             KeyCode::Down => self.change_direction(Direction::Down),
@@ -129,4 +161,34 @@ impl Game for SnakeGame {
     fn tick(&mut self) {
         self.update();
     }
+
+    fn resize(&mut self, width: u16, height: u16) {
+        self.fitting_field = (
+            width
+                .saturating_sub(FIELD_MARGIN)
+                .clamp(MIN_FIELD_WIDTH, FIELD_WIDTH),
+            height
+                .saturating_sub(FIELD_MARGIN)
+                .clamp(MIN_FIELD_HEIGHT, FIELD_HEIGHT),
+        );
+
+        if self.fits_in(self.fitting_field) {
+            (self.field_width, self.field_height) = self.fitting_field;
+        }
+    }
+
+    fn min_size(&self) -> (u16, u16) {
+        (
+            self.field_width + FIELD_MARGIN,
+            self.field_height + FIELD_MARGIN,
+        )
+    }
+
+    fn outcome(&self) -> Option<GameOutcome> {
+        self.game_over.then(|| GameOutcome {
+            won: self.food_eaten > 0,
+            score: self.food_eaten * 5,
+            hangman: None,
+        })
+    }
 }
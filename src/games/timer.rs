@@ -0,0 +1,131 @@
+use std::time::{Duration, Instant};
+
+pub const GUESS_TIME: Duration = Duration::from_secs(15);
+pub const BLITZ_TIME: Duration = Duration::from_secs(180);
+// ticks stop while the game is paused, a longer gap than this doesn't count
+const MAX_TICK_GAP: Duration = Duration::from_millis(250);
+
+#[derive(Clone, Copy, PartialEq, Default)]
+pub enum TimeMode {
+    #[default]
+    Off,
+    // every guess has to come within GUESS_TIME, or it costs a life
+    PerGuess,
+    // as many words as possible within BLITZ_TIME
+    Blitz,
+}
+
+impl TimeMode {
+    pub const ALL: [TimeMode; 3] = [TimeMode::Off, TimeMode::PerGuess, TimeMode::Blitz];
+
+    pub fn name(&self) -> &str {
+        match self {
+            TimeMode::Off => "geen haast",
+            TimeMode::PerGuess => "15 seconden per letter",
+            TimeMode::Blitz => "blitz: 3 minuten",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        let index = Self::ALL.iter().position(|mode| mode == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+
+    fn limit(&self) -> Duration {
+        match self {
+            TimeMode::Off => Duration::ZERO,
+            TimeMode::PerGuess => GUESS_TIME,
+            TimeMode::Blitz => BLITZ_TIME,
+        }
+    }
+}
+
+// Counts playing time from tick to tick, so time spent paused doesn't count
+#[derive(Clone, Copy, Default)]
+pub struct Stopwatch {
+    pub elapsed: Duration,
+    last_tick: Option<Instant>,
+}
+
+impl Stopwatch {
+    // The time since the last tick
+    pub fn advance(&mut self) -> Duration {
+        let now = Instant::now();
+        let delta = self
+            .last_tick
+            .map_or(Duration::ZERO, |last| (now - last).min(MAX_TICK_GAP));
+        self.last_tick = Some(now);
+
+        self.elapsed += delta;
+        delta
+    }
+
+    // The next tick starts counting again, without the time in between
+    pub fn stop(&mut self) {
+        self.last_tick = None;
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct Timer {
+    pub mode: TimeMode,
+    pub time_left: Duration,
+    // playing time of this round, or of the whole blitz
+    pub played: Stopwatch,
+    pub words_solved: u32,
+    // the blitz clock ran out
+    pub run_over: bool,
+}
+
+impl Timer {
+    pub fn new(mode: TimeMode) -> Self {
+        Self {
+            mode,
+            time_left: mode.limit(),
+            played: Stopwatch::default(),
+            words_solved: 0,
+            run_over: false,
+        }
+    }
+
+    // A blitz keeps running over the words, everything else starts over
+    pub fn next_round(mut self) -> Self {
+        match self.mode {
+            TimeMode::Blitz if !self.run_over => {
+                self.played.stop();
+                self
+            }
+            mode => Self::new(mode),
+        }
+    }
+
+    // Counts the time since the last tick, true once the time is up
+    pub fn advance(&mut self) -> bool {
+        let delta = self.played.advance();
+        self.time_left = self.time_left.saturating_sub(delta);
+        self.time_left.is_zero()
+    }
+
+    // The next letter gets the full countdown again
+    pub fn restart_countdown(&mut self) {
+        if self.mode == TimeMode::PerGuess {
+            self.time_left = GUESS_TIME;
+        }
+    }
+
+    pub fn ratio_used(&self) -> f64 {
+        let limit = self.mode.limit().as_secs_f64();
+        if limit == 0.0 {
+            return 0.0;
+        }
+        1.0 - self.time_left.as_secs_f64() / limit
+    }
+
+    pub fn words_per_minute(&self) -> f64 {
+        let minutes = self.played.elapsed.as_secs_f64() / 60.0;
+        if minutes == 0.0 {
+            return 0.0;
+        }
+        self.words_solved as f64 / minutes
+    }
+}
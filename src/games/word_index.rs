@@ -0,0 +1,183 @@
+use crate::games::letters::LetterRules;
+use crate::games::word_packs::{
+    load_packs, sorted_paths, WordEntry, WordPack, PHRASE_PACKS_DIR, WORD_PACKS_DIR,
+};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+// The word packs, loaded once and looked up by length and by letter instead
+// of reading every file again each round. Edits to the pack files are picked
+// up by refresh.
+#[derive(Default)]
+pub struct WordIndex {
+    pub packs: Vec<WordPack>,
+    // what the index was built for, None before the first load
+    loaded_for: Option<(bool, LetterRules)>,
+    // (pack, entry) for every word, the ids below point in here
+    words: Vec<(usize, usize)>,
+    // the keys that reveal each word, None if it has pieces that are always shown
+    keys: Vec<Option<Vec<char>>>,
+    // plain words by how many keys they have
+    by_length: BTreeMap<usize, Vec<usize>>,
+    // plain words with this key in them
+    by_letter: HashMap<char, HashSet<usize>>,
+    // when the pack files were last changed, to notice edits
+    stamps: Vec<(PathBuf, Option<SystemTime>)>,
+}
+
+fn file_stamps(phrases: bool) -> Vec<(PathBuf, Option<SystemTime>)> {
+    let dir = if phrases { PHRASE_PACKS_DIR } else { WORD_PACKS_DIR };
+    sorted_paths(dir)
+        .into_iter()
+        .map(|path| {
+            let modified = path.metadata().and_then(|meta| meta.modified()).ok();
+            (path, modified)
+        })
+        .collect()
+}
+
+impl WordIndex {
+    pub fn load(phrases: bool, rules: LetterRules) -> Self {
+        let mut index = Self::default();
+        index.refresh(phrases, rules);
+        index
+    }
+
+    // Builds the index again if the packs, the letter rules or the files changed
+    pub fn refresh(&mut self, phrases: bool, rules: LetterRules) {
+        let stamps = file_stamps(phrases);
+        if self.loaded_for == Some((phrases, rules)) && self.stamps == stamps {
+            return;
+        }
+
+        *self = Self {
+            loaded_for: Some((phrases, rules)),
+            stamps,
+            ..Self::from_packs(load_packs(phrases), rules)
+        };
+    }
+
+    // The lookups for these packs, whatever files they came from
+    fn from_packs(packs: Vec<WordPack>, rules: LetterRules) -> Self {
+        let mut index = Self {
+            packs,
+            ..Self::default()
+        };
+
+        for (pack_index, pack) in index.packs.iter().enumerate() {
+            for (entry_index, entry) in pack.words.iter().enumerate() {
+                let id = index.words.len();
+                let keys = rules
+                    .units(entry.word())
+                    .iter()
+                    .map(|unit| rules.key_for_unit(unit))
+                    .collect::<Option<Vec<char>>>();
+
+                if let Some(keys) = &keys {
+                    index.by_length.entry(keys.len()).or_default().push(id);
+                    for key in keys {
+                        index.by_letter.entry(*key).or_default().insert(id);
+                    }
+                }
+                index.words.push((pack_index, entry_index));
+                index.keys.push(keys);
+            }
+        }
+        index
+    }
+
+    fn entry(&self, id: usize) -> (&WordPack, &WordEntry) {
+        let (pack, entry) = self.words[id];
+        let pack = &self.packs[pack];
+        (pack, &pack.words[entry])
+    }
+
+    pub fn entries(&self) -> impl Iterator<Item = (&WordPack, &WordEntry)> {
+        (0..self.words.len()).map(|id| self.entry(id))
+    }
+
+    // Plain words, so every piece of them is a letter to guess
+    pub fn of_length(&self, length: usize) -> impl Iterator<Item = (&WordPack, &WordEntry)> {
+        self.by_length
+            .get(&length)
+            .into_iter()
+            .flatten()
+            .map(|id| self.entry(*id))
+    }
+
+    pub fn lengths(&self) -> impl Iterator<Item = usize> + '_ {
+        self.by_length.keys().copied()
+    }
+
+    // The spellings that fit what has been revealed so far: the revealed keys
+    // in their spots, and none of the other guessed keys anywhere else
+    pub fn matching(&self, pattern: &[Option<char>], guessed: &[char]) -> Vec<Vec<char>> {
+        let ruled_out: HashSet<usize> = guessed
+            .iter()
+            .filter(|key| !pattern.contains(&Some(**key)))
+            .filter_map(|key| self.by_letter.get(key))
+            .flatten()
+            .copied()
+            .collect();
+
+        let mut words: Vec<Vec<char>> = self
+            .by_length
+            .get(&pattern.len())
+            .into_iter()
+            .flatten()
+            .filter(|id| !ruled_out.contains(id))
+            .filter_map(|id| self.keys[*id].as_ref())
+            .filter(|keys| {
+                keys.iter().zip(pattern).all(|(key, slot)| match slot {
+                    Some(revealed) => key == revealed,
+                    None => !guessed.contains(key),
+                })
+            })
+            .cloned()
+            .collect();
+        words.sort();
+        words.dedup();
+        words
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn index(words: &[&str]) -> WordIndex {
+        let pack = WordPack {
+            name: "Test".to_string(),
+            category: "test".to_string(),
+            language: "nl".to_string(),
+            difficulty: None,
+            words: words.iter().map(|word| WordEntry::Plain(word.to_string())).collect(),
+        };
+        WordIndex::from_packs(vec![pack], LetterRules::default())
+    }
+
+    fn spelled(words: &[Vec<char>]) -> Vec<String> {
+        words.iter().map(|keys| keys.iter().collect()).collect()
+    }
+
+    #[test]
+    fn matches_the_revealed_letters_in_their_spots() {
+        let index = index(&["kat", "kit", "kast", "pot", "kat"]);
+        let words = index.matching(&[Some('k'), None, Some('t')], &['k', 't']);
+        assert_eq!(spelled(&words), ["kat", "kit"]);
+    }
+
+    #[test]
+    fn leaves_out_words_with_a_missed_letter() {
+        let index = index(&["kat", "kit", "pot"]);
+        assert_eq!(spelled(&index.matching(&[None; 3], &['a'])), ["kit", "pot"]);
+    }
+
+    #[test]
+    fn a_revealed_letter_is_not_hiding_in_another_spot() {
+        let index = index(&["aap", "kap", "map"]);
+        let words = index.matching(&[None, Some('a'), None], &['a']);
+        assert_eq!(spelled(&words), ["kap", "map"]);
+    }
+}
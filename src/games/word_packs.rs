@@ -0,0 +1,310 @@
+use serde::{Deserialize, Serialize};
+use std::{
+    fs::File,
+    io::{BufReader, prelude::*},
+    path::Path,
+};
+
+pub const WORD_PACKS_DIR: &str = "data/hangman";
+pub const PHRASE_PACKS_DIR: &str = "data/hangman/zinnen";
+// what the selection is called when all packs are in the mix
+pub const ALL_PACKS: &str = "Alles";
+pub const ALL_PHRASES: &str = "Alle zinnen";
+
+// Dutch letters from most to least common, used to guess how hard a word is
+pub const LETTER_FREQUENCY: &str = "enatirodslgvhkmubpwjczfxyq";
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Difficulty {
+    Makkelijk,
+    Gemiddeld,
+    Moeilijk,
+}
+
+impl Difficulty {
+    pub const ALL: [Difficulty; 3] = [
+        Difficulty::Makkelijk,
+        Difficulty::Gemiddeld,
+        Difficulty::Moeilijk,
+    ];
+
+    pub fn name(&self) -> &str {
+        match self {
+            Difficulty::Makkelijk => "makkelijk",
+            Difficulty::Gemiddeld => "gemiddeld",
+            Difficulty::Moeilijk => "moeilijk",
+        }
+    }
+
+    // Short words give little away and rare letters are hard to think of
+    pub fn of_word(word: &str) -> Self {
+        // a capital is the same letter, just like in words stats
+        let mut letters: Vec<char> = word
+            .chars()
+            .filter(|c| c.is_alphabetic())
+            .map(|c| c.to_lowercase().next().unwrap_or(c))
+            .collect();
+        let length = letters.len();
+        letters.sort_unstable();
+        letters.dedup();
+
+        // anything in the rarer half of the alphabet, or not in it at all
+        let rare_letters = letters
+            .iter()
+            .filter(|c| {
+                LETTER_FREQUENCY
+                    .find(**c)
+                    .is_none_or(|rank| rank >= LETTER_FREQUENCY.len() / 2)
+            })
+            .count();
+        let length_penalty = match length {
+            0..=5 => 3.0,
+            6..=8 => 1.5,
+            _ => 0.0,
+        };
+
+        match rare_letters as f32 * 1.5 + length_penalty {
+            score if score < 4.0 => Difficulty::Makkelijk,
+            score if score < 6.0 => Difficulty::Gemiddeld,
+            _ => Difficulty::Moeilijk,
+        }
+    }
+}
+
+// A word is either just the word, or an object with extra details
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(untagged)]
+pub enum WordEntry {
+    Plain(String),
+    Detailed {
+        word: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        difficulty: Option<Difficulty>,
+        // costs a guess to see
+        #[serde(skip_serializing_if = "Option::is_none")]
+        hint: Option<String>,
+        // shown after the game, what the word means or where the inside joke comes from
+        #[serde(skip_serializing_if = "Option::is_none")]
+        definition: Option<String>,
+        // who said or sang it, for phrases
+        #[serde(skip_serializing_if = "Option::is_none")]
+        attribution: Option<String>,
+    },
+}
+
+impl WordEntry {
+    pub fn word(&self) -> &str {
+        match self {
+            WordEntry::Plain(word) => word,
+            WordEntry::Detailed { word, .. } => word,
+        }
+    }
+
+    pub fn hint(&self) -> Option<&str> {
+        match self {
+            WordEntry::Detailed { hint, .. } => hint.as_deref(),
+            WordEntry::Plain(_) => None,
+        }
+    }
+
+    pub fn definition(&self) -> Option<&str> {
+        match self {
+            WordEntry::Detailed { definition, .. } => definition.as_deref(),
+            WordEntry::Plain(_) => None,
+        }
+    }
+
+    pub fn attribution(&self) -> Option<&str> {
+        match self {
+            WordEntry::Detailed { attribution, .. } => attribution.as_deref(),
+            WordEntry::Plain(_) => None,
+        }
+    }
+}
+
+fn dutch() -> String {
+    "nl".to_string()
+}
+
+// Serialize is for `suus-game-hub words import`, which writes packs back out
+#[derive(Serialize, Deserialize, Clone)]
+pub struct WordPack {
+    pub name: String,
+    pub category: String,
+    #[serde(default = "dutch")]
+    pub language: String,
+    // applies to every word that doesn't set its own
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub difficulty: Option<Difficulty>,
+    pub words: Vec<WordEntry>,
+}
+
+impl WordPack {
+    pub fn difficulty_of(&self, entry: &WordEntry) -> Difficulty {
+        let own = match entry {
+            WordEntry::Detailed { difficulty, .. } => *difficulty,
+            WordEntry::Plain(_) => None,
+        };
+
+        own.or(self.difficulty)
+            .unwrap_or_else(|| Difficulty::of_word(entry.word()))
+    }
+}
+
+#[derive(Deserialize)]
+struct Phrase {
+    text: String,
+    attribution: Option<String>,
+    difficulty: Option<Difficulty>,
+    hint: Option<String>,
+    definition: Option<String>,
+}
+
+// Sayings and song lines, each with who they come from
+#[derive(Deserialize)]
+struct PhrasePack {
+    name: String,
+    category: String,
+    #[serde(default = "dutch")]
+    language: String,
+    difficulty: Option<Difficulty>,
+    phrases: Vec<Phrase>,
+}
+
+impl From<PhrasePack> for WordPack {
+    fn from(pack: PhrasePack) -> Self {
+        Self {
+            name: pack.name,
+            category: pack.category,
+            language: pack.language,
+            difficulty: pack.difficulty,
+            words: pack
+                .phrases
+                .into_iter()
+                .map(|phrase| WordEntry::Detailed {
+                    word: phrase.text,
+                    difficulty: phrase.difficulty,
+                    hint: phrase.hint,
+                    definition: phrase.definition,
+                    attribution: phrase.attribution,
+                })
+                .collect(),
+        }
+    }
+}
+
+// Which words a game of hangman picks from, None means anything goes
+#[derive(Clone, Default)]
+pub struct WordSelection {
+    pub pack: Option<String>,
+    pub difficulty: Option<Difficulty>,
+    // sayings and song lines instead of single words
+    pub phrases: bool,
+}
+
+impl WordSelection {
+    pub fn allows(&self, pack: &WordPack, entry: &WordEntry) -> bool {
+        self.pack.as_ref().is_none_or(|name| *name == pack.name)
+            && self
+                .difficulty
+                .is_none_or(|difficulty| pack.difficulty_of(entry) == difficulty)
+    }
+
+    // The pack, or all of them together. The word of the day and the high
+    // scores go by this, whatever the difficulty.
+    pub fn name(&self) -> String {
+        match &self.pack {
+            Some(pack) => pack.clone(),
+            None if self.phrases => ALL_PHRASES.to_string(),
+            None => ALL_PACKS.to_string(),
+        }
+    }
+}
+
+// The words in a text file, one per line, skipping blank lines
+pub fn get_lines(filename: impl AsRef<Path>) -> std::io::Result<Vec<String>> {
+    let buf = BufReader::new(File::open(filename)?);
+    let mut lines = Vec::new();
+    for line in buf.lines() {
+        let line = line?.trim().to_string();
+        if !line.is_empty() {
+            lines.push(line);
+        }
+    }
+    Ok(lines)
+}
+
+// A plain .txt file is a pack too, one word per line, named after the file
+fn load_text_pack(path: &Path) -> Option<WordPack> {
+    let stem = path.file_stem()?.to_str()?;
+    let mut name = stem.to_string();
+    if let Some(first) = name.get_mut(0..1) {
+        first.make_ascii_uppercase();
+    }
+
+    Some(WordPack {
+        name,
+        category: stem.to_string(),
+        language: dutch(),
+        difficulty: None,
+        words: get_lines(path).ok()?.into_iter().map(WordEntry::Plain).collect(),
+    })
+}
+
+pub fn sorted_paths(dir: &str) -> Vec<std::path::PathBuf> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    let mut paths: Vec<_> = entries.filter_map(|e| e.ok()).map(|e| e.path()).collect();
+    paths.sort();
+    paths
+}
+
+pub fn load_packs(phrases: bool) -> Vec<WordPack> {
+    if phrases {
+        load_phrase_packs()
+    } else {
+        load_word_packs()
+    }
+}
+
+// Every .json and .txt pack in data/hangman, sorted by file name
+pub fn load_word_packs() -> Vec<WordPack> {
+    let paths = sorted_paths(WORD_PACKS_DIR);
+
+    paths
+        .iter()
+        .filter_map(|path| load_pack_file(path))
+        .filter(|pack| !pack.words.is_empty())
+        .collect()
+}
+
+pub fn load_pack_file(path: &Path) -> Option<WordPack> {
+    let mut pack: WordPack = match path.extension()?.to_str()? {
+        "json" => serde_json::from_str(&std::fs::read_to_string(path).ok()?).ok()?,
+        "txt" => load_text_pack(path)?,
+        _ => return None,
+    };
+
+    // an empty word would be a game with nothing to guess
+    pack.words.retain(|entry| !entry.word().trim().is_empty());
+    Some(pack)
+}
+
+// A phrase pack file as a word pack, with every phrase as one entry
+pub fn parse_phrase_pack(contents: &str) -> serde_json::Result<WordPack> {
+    serde_json::from_str::<PhrasePack>(contents).map(WordPack::from)
+}
+
+// Every .json phrase pack in data/hangman/zinnen, sorted by file name
+pub fn load_phrase_packs() -> Vec<WordPack> {
+    sorted_paths(PHRASE_PACKS_DIR)
+        .iter()
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .filter_map(|path| std::fs::read_to_string(path).ok())
+        .filter_map(|file| parse_phrase_pack(&file).ok())
+        .filter(|pack| !pack.words.is_empty())
+        .collect()
+}
@@ -0,0 +1,113 @@
+use chrono::{Datelike, NaiveDate};
+use serde::Deserialize;
+
+const GREETINGS_PATH: &str = "data/greetings.json";
+
+// How long each message stays on the dashboard before the next one shows up
+pub const ROTATE_SECONDS: u64 = 12;
+
+#[derive(Deserialize, Clone)]
+pub struct Theme {
+    // "MM-DD", inclusive; a range may wrap around new year
+    pub from: Option<String>,
+    pub to: Option<String>,
+    pub title: String,
+    pub subtitle: String,
+    pub color: [u8; 3],
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            from: None,
+            to: None,
+            title: "PIPIS GAME HUB".to_string(),
+            subtitle: "💖 Susan's Game Hub 💖".to_string(),
+            color: [255, 105, 180],
+        }
+    }
+}
+
+#[derive(Deserialize, Clone)]
+pub struct Message {
+    pub text: String,
+    // "MM-DD" for every year (birthdays), or "YYYY-MM-DD" for one day only
+    pub date: Option<String>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default)]
+pub struct Greetings {
+    pub default_theme: Theme,
+    pub themes: Vec<Theme>,
+    pub messages: Vec<Message>,
+}
+
+pub fn load_greetings() -> Greetings {
+    std::fs::read_to_string(GREETINGS_PATH)
+        .ok()
+        .and_then(|file| serde_json::from_str(&file).ok())
+        .unwrap_or_default()
+}
+
+// "MM-DD" as a number that sorts by day of the year, e.g. 12-24 -> 1224
+fn month_day(text: &str) -> Option<u32> {
+    let (month, day) = text.split_once('-')?;
+    Some(month.parse::<u32>().ok()? * 100 + day.parse::<u32>().ok()?)
+}
+
+fn matches_date(date: &str, today: NaiveDate) -> bool {
+    match NaiveDate::parse_from_str(date, "%Y-%m-%d") {
+        Ok(exact) => exact == today,
+        Err(_) => month_day(date) == Some(today.month() * 100 + today.day()),
+    }
+}
+
+impl Theme {
+    fn is_active(&self, today: NaiveDate) -> bool {
+        let (Some(from), Some(to)) = (
+            self.from.as_deref().and_then(month_day),
+            self.to.as_deref().and_then(month_day),
+        ) else {
+            return false;
+        };
+
+        let now = today.month() * 100 + today.day();
+        if from <= to {
+            (from..=to).contains(&now)
+        } else {
+            now >= from || now <= to
+        }
+    }
+}
+
+impl Greetings {
+    pub fn theme_for(&self, today: NaiveDate) -> &Theme {
+        self.themes
+            .iter()
+            .find(|theme| theme.is_active(today))
+            .unwrap_or(&self.default_theme)
+    }
+
+    // Today's special messages win; otherwise rotate through the undated ones
+    pub fn message_for(&self, today: NaiveDate, unix_seconds: u64) -> Option<&Message> {
+        let dated: Vec<&Message> = self
+            .messages
+            .iter()
+            .filter(|m| m.date.as_deref().is_some_and(|d| matches_date(d, today)))
+            .collect();
+
+        let pool = if dated.is_empty() {
+            self.messages.iter().filter(|m| m.date.is_none()).collect()
+        } else {
+            dated
+        };
+
+        if pool.is_empty() {
+            return None;
+        }
+
+        let index = (unix_seconds / ROTATE_SECONDS) as usize % pool.len();
+        Some(pool[index])
+    }
+}
@@ -1,17 +1,55 @@
 use crate::games::{
     adventure::Adventure,
+    daily::Daily,
+    gallows::{gallows_named, load_gallows},
     hangman::HangmanGame,
-    // snake::SnakeGame,
+    hot_seat::HotSeat,
+    reverse_hangman::ReverseHangmanGame,
+    snake::SnakeGame,
+    timer::{TimeMode, Timer},
     // twozerofoureight::Game2048,
     Game,
+    GameOutcome,
     GameType,
 };
+use crate::games::word_packs::{load_packs, load_word_packs, Difficulty, WordPack, WordSelection};
+use crate::greetings::{load_greetings, Greetings};
+use crate::profile::{Profile, SharedProfile};
+use crate::split::{SplitGames, SplitScreen};
+use crate::tournament::{load_playlists, Playlist, PlaylistStep, Tournament, TournamentPhase};
+use crate::utils::clipboard::copy_to_clipboard;
+use crate::utils::screenshot::save_screenshot;
+use crate::utils::time::{day_key, today};
+use ratatui::buffer::Buffer;
 use ratatui::crossterm::event::KeyCode;
+use std::collections::HashMap;
+use std::io::Write;
+use std::sync::{MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+
+// Assumed until the terminal tells us its real size
+const DEFAULT_TERMINAL_SIZE: (u16, u16) = (80, 24);
+
+// How much play time may pile up before it is written to the profile
+const PLAY_TIME_SAVE_INTERVAL: Duration = Duration::from_secs(30);
+
+// Away from the games, or not touching a key, for this long counts as a break
+const BREAK_TIME: Duration = Duration::from_secs(5 * 60);
+
+// How long a notice stays at the bottom of the screen
+const NOTICE_TIME: Duration = Duration::from_secs(3);
+// and one about the profile, which is easy to miss while the hub starts
+const PROFILE_NOTICE_TIME: Duration = Duration::from_secs(10);
 
 #[derive(PartialEq)]
 pub enum Screen {
     Dashboard,
     Game,
+    Stats,
+    Tournament,
+    Split,
+    HangmanSetup,
+    HangmanStats,
 }
 
 pub enum MenuDirection {
@@ -19,43 +57,299 @@ pub enum MenuDirection {
     Right,
 }
 
+// Time spent inside games during this run of the hub
+pub struct PlayTime {
+    pub session: Duration,
+    pub continuous: Duration,
+    pub per_game: HashMap<String, Duration>,
+    unsaved: Duration,
+    last_update: Instant,
+    last_input: Instant,
+    // time outside the games since the last time you played
+    resting: Duration,
+}
+
+impl PlayTime {
+    fn new() -> Self {
+        Self {
+            session: Duration::ZERO,
+            continuous: Duration::ZERO,
+            per_game: HashMap::new(),
+            unsaved: Duration::ZERO,
+            last_update: Instant::now(),
+            last_input: Instant::now(),
+            resting: Duration::ZERO,
+        }
+    }
+}
+
 pub struct GameHub {
     pub current_screen: Screen,
     pub selected_game_index: usize,
     pub current_game: Option<Box<dyn Game>>,
+    pub current_game_type: Option<GameType>,
     pub available_games: Vec<GameType>,
+    pub playlists: Vec<Playlist>,
+    pub selected_playlist_index: usize,
+    pub tournament: Option<Tournament>,
+    pub split: Option<SplitScreen>,
+    profile: SharedProfile,
+    pub play_time: PlayTime,
+    pub show_break_reminder: bool,
+    pub greetings: Greetings,
+    pub terminal_size: (u16, u16),
+    pub word_packs: Vec<WordPack>,
+    // 0 is every pack / every difficulty, the rest are offset by one
+    pub selected_pack_index: usize,
+    pub selected_difficulty_index: usize,
+    pub hangman_phrases: bool,
+    pub hangman_evil: bool,
+    pub hangman_time: TimeMode,
+    // a short message at the bottom of the screen, like where a screenshot went,
+    // until it expires
+    pub notice: Option<(String, Instant)>,
+    // so a finished round only ends up in the stats once
+    round_recorded: bool,
+    // F12 was pressed, the next frame that gets drawn is saved
+    screenshot_requested: bool,
 }
 
 impl GameHub {
     pub fn new() -> Self {
+        let (profile, problem) = Profile::load();
+        let mut hub = Self::with_profile(profile.shared());
+        if let Some(problem) = problem {
+            hub.notify_for(problem, PROFILE_NOTICE_TIME);
+        }
+        hub
+    }
+
+    // A hub on a profile that other hubs may be using too, like telnet sessions
+    pub fn with_profile(profile: SharedProfile) -> Self {
         Self {
             current_screen: Screen::Dashboard,
             selected_game_index: 0,
             current_game: None,
-            available_games: vec![GameType::Hangman, GameType::Adventure],
+            current_game_type: None,
+            available_games: vec![GameType::Hangman, GameType::Snake, GameType::Adventure],
+            playlists: load_playlists(),
+            selected_playlist_index: 0,
+            tournament: None,
+            split: None,
+            profile,
+            play_time: PlayTime::new(),
+            show_break_reminder: false,
+            greetings: load_greetings(),
+            terminal_size: DEFAULT_TERMINAL_SIZE,
+            word_packs: load_word_packs(),
+            selected_pack_index: 0,
+            selected_difficulty_index: 0,
+            hangman_phrases: false,
+            hangman_evil: false,
+            hangman_time: TimeMode::Off,
+            notice: None,
+            round_recorded: false,
+            screenshot_requested: false,
         }
     }
 
     pub fn update(&mut self) {
+        self.track_play_time();
+
+        if self.notice.as_ref().is_some_and(|(_, until)| Instant::now() >= *until) {
+            self.notice = None;
+        }
+
+        // the game is paused while the break reminder is up, or while it doesn't fit
+        if self.show_break_reminder || !self.fits_terminal() {
+            return;
+        }
+
         if let Some(game) = &mut self.current_game {
             game.tick();
         }
+        self.record_finished_round();
+
+        if let Some(split) = &mut self.split {
+            split.tick();
+        }
+    }
+
+    pub fn notify(&mut self, text: String) {
+        self.notify_for(text, NOTICE_TIME);
+    }
+
+    pub fn notify_for(&mut self, text: String, time: Duration) {
+        self.notice = Some((text, Instant::now() + time));
+    }
+
+    // Whatever the game wants copied goes to the terminal the hub draws on
+    pub fn send_clipboard(&mut self, terminal: &mut impl Write) {
+        let Some(text) = self.current_game.as_mut().and_then(|game| game.take_clipboard()) else {
+            return;
+        };
+        if let Err(error) = copy_to_clipboard(terminal, &text) {
+            self.notify(format!("📋 kopiëren lukte niet: {}", error));
+        }
+    }
+
+    // Another session may hold it for a moment, and one that panicked while
+    // holding it shouldn't take everyone's stats down with it
+    pub fn profile(&self) -> MutexGuard<'_, Profile> {
+        self.profile.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    // Copying the whole frame is only worth it when someone asks for it, so
+    // F12 just asks and the frame gets saved once it's drawn
+    pub fn request_screenshot(&mut self) {
+        self.screenshot_requested = true;
+    }
+
+    // A failed screenshot is worth a notice, not the end of the session
+    pub fn save_requested_screenshot(&mut self, frame: &Buffer) {
+        if !std::mem::take(&mut self.screenshot_requested) {
+            return;
+        }
+        let notice = match save_screenshot(frame) {
+            Ok(()) => "📸 screenshot opgeslagen in screenshots/".to_string(),
+            Err(error) => format!("📸 screenshot lukte niet: {}", error),
+        };
+        self.notify(notice);
+    }
+
+    pub fn resize(&mut self, width: u16, height: u16) {
+        self.terminal_size = (width, height);
+
+        if let Some(game) = &mut self.current_game {
+            game.resize(width, height);
+        }
+        if let Some(split) = &mut self.split {
+            split.resize(width, height);
+        }
+    }
+
+    // Smallest terminal the current screen can be drawn in
+    pub fn min_size(&self) -> (u16, u16) {
+        match self.current_screen {
+            Screen::Dashboard => (40, 21),
+            Screen::Game => self
+                .current_game
+                .as_ref()
+                .map_or((40, 12), |game| game.min_size()),
+            Screen::Stats => (50, 18),
+            Screen::Tournament => (56, 20),
+            Screen::HangmanSetup => (50, 20),
+            Screen::HangmanStats => (50, 21),
+            Screen::Split => self
+                .split
+                .as_ref()
+                .map_or((80, 12), |split| split.min_size()),
+        }
+    }
+
+    pub fn fits_terminal(&self) -> bool {
+        let (width, height) = self.terminal_size;
+        let (min_width, min_height) = self.min_size();
+        width >= min_width && height >= min_height
+    }
+
+    fn track_play_time(&mut self) {
+        let now = Instant::now();
+        let delta = now - self.play_time.last_update;
+        self.play_time.last_update = now;
+
+        let playing = matches!(self.current_screen, Screen::Game | Screen::Split);
+        if playing {
+            self.play_time.resting = Duration::ZERO;
+        } else {
+            self.play_time.resting += delta;
+        }
+
+        // a real break, by leaving the games or the keyboard alone, starts a fresh stretch
+        let idle = self.play_time.last_input.elapsed() >= BREAK_TIME;
+        if idle || self.play_time.resting >= BREAK_TIME {
+            self.play_time.continuous = Duration::ZERO;
+        }
+
+        if !playing || self.show_break_reminder {
+            return;
+        }
+
+        if let Some(game_type) = self.current_game_type {
+            *self
+                .play_time
+                .per_game
+                .entry(game_type.name().to_string())
+                .or_default() += delta;
+        }
+        self.play_time.session += delta;
+        if !idle {
+            self.play_time.continuous += delta;
+        }
+        self.play_time.unsaved += delta;
+
+        if self.play_time.unsaved >= PLAY_TIME_SAVE_INTERVAL {
+            self.save_profile();
+        }
+
+        let reminder_minutes = self.profile().settings.break_reminder_minutes;
+        if reminder_minutes > 0 && self.play_time.continuous.as_secs() >= reminder_minutes * 60 {
+            self.show_break_reminder = true;
+        }
+    }
+
+    pub fn save_profile(&mut self) {
+        self.sync_game_progress();
+        let seconds = self.play_time.unsaved.as_secs();
+        self.play_time.unsaved -= Duration::from_secs(seconds);
+        self.profile().add_play_seconds(&day_key(today()), seconds);
+
+        // losing a bit of play time is not worth crashing the game over
+        let _ = self.profile().save();
+    }
+
+    pub fn played_today(&self) -> Duration {
+        let saved = self.profile().play_seconds_on(&day_key(today()));
+        Duration::from_secs(saved) + self.play_time.unsaved
+    }
+
+    // Every way into hangman starts here, so no game goes without your
+    // settings or the words you already had
+    fn hangman_game(&self, selection: WordSelection) -> Box<HangmanGame> {
+        let profile = self.profile();
+        let mut game = Box::new(HangmanGame::with_selection(selection));
+        game.rules = profile.settings.hangman_letters;
+        game.art = gallows_named(&profile.settings.gallows);
+        game.load_history(&profile.hangman_history);
+        game.best_points = profile.hangman.best_points(&game.selection.name());
+        game
+    }
+
+    fn play_hangman(&mut self, mut game: Box<HangmanGame>) {
+        game.restart();
+        let (width, height) = self.terminal_size;
+        game.resize(width, height);
+
+        self.current_game = Some(game);
+        self.current_game_type = Some(GameType::Hangman);
+        self.current_screen = Screen::Game;
     }
 
     // Update the create_game method:
     fn create_game(&self, game_type: GameType) -> Box<dyn Game> {
-        match game_type {
+        let (width, height) = self.terminal_size;
+        let mut game: Box<dyn Game> = match game_type {
             GameType::Hangman => {
-                let mut game = Box::new(HangmanGame::new());
+                let mut game = self.hangman_game(WordSelection::default());
                 game.restart();
                 game
             }
 
-            // GameType::Snake => {
-            //     let mut game = Box::new(SnakeGame::new());
-            //     game.restart();
-            //     game
-            // }
+            GameType::Snake => {
+                let mut game = Box::new(SnakeGame::new());
+                game.restart();
+                game
+            }
             // GameType::Game2048 => {
             //     let mut game = Box::new(Game2048::new());
             //     game.restart();
@@ -66,13 +360,56 @@ impl GameHub {
                 game.restart();
                 game
             }
-        }
+        };
+
+        game.resize(width, height);
+        game
     }
+
     pub fn handle_input(&mut self, key: KeyCode) {
+        self.play_time.last_input = Instant::now();
+
+        // any key dismisses the break reminder and starts a fresh stretch
+        if self.show_break_reminder {
+            self.show_break_reminder = false;
+            self.play_time.continuous = Duration::ZERO;
+            return;
+        }
+
+        // a game that doesn't fit is paused, you can only leave the split screen
+        if !self.fits_terminal() {
+            match (&self.current_screen, key) {
+                (Screen::Game, _) => return,
+                (Screen::Split, code) if code != KeyCode::Backspace => return,
+                _ => {}
+            }
+        }
+
         match self.current_screen {
             Screen::Dashboard => self.handle_dashboard_input(key),
             Screen::Game => self.handle_game_input(key),
+            Screen::Stats => self.handle_stats_input(key),
+            Screen::Tournament => self.handle_tournament_input(key),
+            Screen::Split => self.handle_split_input(key),
+            Screen::HangmanSetup => self.handle_hangman_setup_input(key),
+            Screen::HangmanStats => self.handle_hangman_stats_input(key),
+        }
+    }
+
+    // Left clicks only do something inside a game, like the hangman keyboard
+    pub fn handle_click(&mut self, column: u16, row: u16) {
+        self.play_time.last_input = Instant::now();
+        if self.show_break_reminder
+            || !self.fits_terminal()
+            || self.current_screen != Screen::Game
+        {
+            return;
         }
+
+        if let Some(game) = &mut self.current_game {
+            game.handle_click(column, row);
+        }
+        self.record_finished_round();
     }
 
     fn handle_dashboard_input(&mut self, key: KeyCode) {
@@ -88,17 +425,64 @@ impl GameHub {
             KeyCode::Enter => {
                 self.start_selected_game();
             }
+
+            KeyCode::Char('s') => {
+                self.current_screen = Screen::Stats;
+            }
+
+            KeyCode::Char('v') => {
+                self.start_split_screen();
+            }
+
+            KeyCode::Char('t') if !self.playlists.is_empty() => {
+                self.tournament = None;
+                self.current_screen = Screen::Tournament;
+            }
             _ => {}
         }
     }
 
-    fn handle_game_input(&mut self, key: KeyCode) {
+    fn handle_stats_input(&mut self, key: KeyCode) {
         match key {
-            KeyCode::Esc => {
-                // Return to dashboard
+            KeyCode::Backspace | KeyCode::Char('s') | KeyCode::Char('q') => {
                 self.current_screen = Screen::Dashboard;
-                self.current_game = None;
             }
+            KeyCode::Char('g') => self.current_screen = Screen::HangmanStats,
+            _ => {}
+        }
+    }
+
+    fn handle_hangman_stats_input(&mut self, key: KeyCode) {
+        let pack_options = self.word_packs.len() + 1;
+
+        match key {
+            KeyCode::Backspace | KeyCode::Char('g') | KeyCode::Char('q') => {
+                self.current_screen = Screen::Stats;
+            }
+            // the high scores of another pack, the same pick as in the hangman setup
+            KeyCode::Right | KeyCode::Char('l') => self.step_pack(1),
+            KeyCode::Left | KeyCode::Char('h') => self.step_pack(pack_options - 1),
+            _ => {}
+        }
+    }
+
+    // The first option is all packs together
+    fn step_pack(&mut self, steps: usize) {
+        let pack_options = self.word_packs.len() + 1;
+        self.selected_pack_index = (self.selected_pack_index + steps) % pack_options;
+    }
+
+    fn handle_game_input(&mut self, key: KeyCode) {
+        if self.tournament.is_some() {
+            self.handle_tournament_game_input(key);
+            return;
+        }
+
+        let over = self.current_game.as_ref().is_some_and(|game| game.is_over());
+        match key {
+            // Return to dashboard
+            KeyCode::Esc => self.leave_game(),
+            KeyCode::Backspace if over => self.leave_game(),
             KeyCode::F(5) => {
                 // Restart current game
                 if let Some(game) = &mut self.current_game {
@@ -112,6 +496,44 @@ impl GameHub {
                 }
             }
         }
+
+        self.record_finished_round();
+    }
+
+    fn leave_game(&mut self) {
+        self.sync_game_progress();
+        self.current_screen = Screen::Dashboard;
+        self.current_game = None;
+        self.current_game_type = None;
+    }
+
+    // Keeps the profile up to date with the current game
+    fn record_finished_round(&mut self) {
+        let Some(outcome) = self.current_game.as_ref().and_then(|game| game.outcome()) else {
+            self.round_recorded = false;
+            return;
+        };
+        if self.round_recorded {
+            return;
+        }
+        self.round_recorded = true;
+        self.sync_game_progress();
+
+        // tournament rounds are kept by the tournament itself
+        if self.tournament.is_none() {
+            self.profile().record_round(&outcome);
+        }
+        let _ = self.profile().save();
+    }
+
+    // At the end of a round and when you leave, not on every tick
+    fn sync_game_progress(&self) {
+        if let Some(game) = &self.current_game {
+            game.save_progress(&mut self.profile());
+        }
+        if let Some(split) = &self.split {
+            split.save_progress(&mut self.profile());
+        }
     }
 
     fn cycle_game_selection(&mut self, dir: MenuDirection) {
@@ -122,7 +544,7 @@ impl GameHub {
                         return;
                     }
 
-                    self.selected_game_index = self.selected_game_index - 1;
+                    self.selected_game_index -= 1;
                 }
                 MenuDirection::Right => {
                     self.selected_game_index =
@@ -133,12 +555,292 @@ impl GameHub {
     }
 
     fn start_selected_game(&mut self) {
+        // hangman first asks which words you want
+        if let Some(GameType::Hangman) = self.available_games.get(self.selected_game_index) {
+            self.word_packs = load_packs(self.hangman_phrases);
+            self.selected_pack_index = self.selected_pack_index.min(self.word_packs.len());
+            self.current_screen = Screen::HangmanSetup;
+            return;
+        }
+
         if let Some(game_type) = self.available_games.get(self.selected_game_index) {
-            self.current_game = Some(self.create_game(game_type.clone()));
+            self.current_game = Some(self.create_game(*game_type));
+            self.current_game_type = Some(*game_type);
             self.current_screen = Screen::Game;
         }
     }
 
+    pub fn word_selection(&self) -> WordSelection {
+        WordSelection {
+            pack: self
+                .selected_pack_index
+                .checked_sub(1)
+                .and_then(|i| self.word_packs.get(i))
+                .map(|pack| pack.name.clone()),
+            difficulty: self
+                .selected_difficulty_index
+                .checked_sub(1)
+                .map(|i| Difficulty::ALL[i]),
+            phrases: self.hangman_phrases,
+        }
+    }
+
+    // Counts as played as soon as it starts, so quitting halfway is no way to a second try
+    fn start_daily(&mut self) {
+        let date = today();
+        // every pack has its own word of the day, and so do all of them together
+        let pack = self.word_selection().name();
+        if self.profile().daily.played_on(&pack, &day_key(date)) {
+            return;
+        }
+
+        // difficulty doesn't matter, everyone gets the same word
+        let selection = WordSelection {
+            difficulty: None,
+            ..self.word_selection()
+        };
+        let streak_if_won = self.profile().daily.streak_if_won(&pack, date);
+
+        let mut game = self.hangman_game(selection);
+        game.daily = Some(Daily::new(date, pack.clone(), streak_if_won));
+        // it only counts as tried once there is a word, an empty pack can come back later
+        game.restart();
+        if !game.all_words_exhausted {
+            let mut profile = self.profile();
+            profile.daily.played.insert(pack, day_key(date));
+            let _ = profile.save();
+        }
+        self.play_hangman(game);
+    }
+
+    fn handle_hangman_setup_input(&mut self, key: KeyCode) {
+        let pack_options = self.word_packs.len() + 1;
+        let difficulty_options = Difficulty::ALL.len() + 1;
+
+        match key {
+            KeyCode::Right | KeyCode::Char('l') => self.step_pack(1),
+            KeyCode::Left | KeyCode::Char('h') => self.step_pack(pack_options - 1),
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.selected_difficulty_index =
+                    (self.selected_difficulty_index + 1) % difficulty_options;
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.selected_difficulty_index =
+                    (self.selected_difficulty_index + difficulty_options - 1) % difficulty_options;
+            }
+            KeyCode::Enter => {
+                let mut game = self.hangman_game(self.word_selection());
+                game.evil = self.hangman_evil;
+                game.timer =
+                    (self.hangman_time != TimeMode::Off).then(|| Timer::new(self.hangman_time));
+                self.play_hangman(game);
+            }
+            KeyCode::Char('d') => self.start_daily(),
+            // two players, taking turns typing a word for each other
+            KeyCode::Char('t') => {
+                let mut game = self.hangman_game(WordSelection::default());
+                game.hot_seat = Some(HotSeat::new());
+                self.play_hangman(game);
+            }
+            // the other way around, the computer guesses a word you think of
+            KeyCode::Char('c') => {
+                let rules = self.profile().settings.hangman_letters;
+                let mut game = Box::new(ReverseHangmanGame::new(rules));
+                game.art = gallows_named(&self.profile().settings.gallows);
+                let (width, height) = self.terminal_size;
+                game.resize(width, height);
+
+                self.current_game = Some(game);
+                self.current_game_type = Some(GameType::Hangman);
+                self.current_screen = Screen::Game;
+            }
+            KeyCode::Char('z') => {
+                self.hangman_phrases = !self.hangman_phrases;
+                self.hangman_evil = false;
+                self.word_packs = load_packs(self.hangman_phrases);
+                self.selected_pack_index = 0;
+            }
+            KeyCode::Char('b') => self.hangman_time = self.hangman_time.next(),
+            // evil mode only works on single words
+            KeyCode::Char('e') => {
+                self.hangman_evil = !self.hangman_evil;
+                if self.hangman_phrases {
+                    self.hangman_phrases = false;
+                    self.word_packs = load_packs(false);
+                    self.selected_pack_index = 0;
+                }
+            }
+            KeyCode::Char('g') => {
+                let sets = load_gallows();
+                let mut profile = self.profile();
+                let index = sets.iter().position(|art| art.name == profile.settings.gallows);
+                let next = index.map_or(0, |index| (index + 1) % sets.len());
+                profile.settings.gallows = sets[next].name.clone();
+                let _ = profile.save();
+            }
+            KeyCode::Char('a') => {
+                let mut profile = self.profile();
+                let rules = &mut profile.settings.hangman_letters;
+                rules.fold_accents = !rules.fold_accents;
+                let _ = profile.save();
+            }
+            KeyCode::Char('i') => {
+                let mut profile = self.profile();
+                let rules = &mut profile.settings.hangman_letters;
+                rules.ij_as_one_letter = !rules.ij_as_one_letter;
+                let _ = profile.save();
+            }
+            KeyCode::Backspace | KeyCode::Char('q') => {
+                self.current_screen = Screen::Dashboard;
+            }
+            _ => {}
+        }
+    }
+
+    fn start_split_screen(&mut self) {
+        let Some(game_type) = self.available_games.get(self.selected_game_index) else {
+            return;
+        };
+
+        let games = match game_type {
+            GameType::Hangman => {
+                let left = *self.hangman_game(WordSelection::default());
+                let right = *self.hangman_game(WordSelection::default());
+                SplitGames::Hangman(Box::new([left, right]))
+            }
+            GameType::Snake => SplitGames::Snake(Box::new([SnakeGame::new(), SnakeGame::new()])),
+            _ => return,
+        };
+
+        let mut split = SplitScreen::new(games);
+        let (width, height) = self.terminal_size;
+        split.resize(width, height);
+        self.current_game_type = Some(split.game_type());
+        self.split = Some(split);
+        self.current_screen = Screen::Split;
+    }
+
+    fn handle_split_input(&mut self, key: KeyCode) {
+        let Some(split) = &mut self.split else {
+            return;
+        };
+
+        match key {
+            KeyCode::Backspace => {
+                self.sync_game_progress();
+                self.split = None;
+                self.current_game_type = None;
+                self.current_screen = Screen::Dashboard;
+            }
+            KeyCode::F(5) => split.restart(),
+            KeyCode::Enter if split.result.is_some() => split.restart(),
+            _ => split.handle_input(key),
+        }
+    }
+
+    // In a tournament the round only ends through the hub, so no restarting or skipping
+    fn handle_tournament_game_input(&mut self, key: KeyCode) {
+        let Some(game) = &mut self.current_game else {
+            return;
+        };
+
+        let outcome = match (game.outcome(), key) {
+            (Some(outcome), KeyCode::Enter) => outcome,
+            // giving up, for a round that's stuck or just going nowhere
+            (None, KeyCode::F(2)) => GameOutcome {
+                won: false,
+                score: 0,
+                hangman: None,
+            },
+            (None, key) if key != KeyCode::F(5) => return game.handle_input(key),
+            _ => return,
+        };
+
+        if let Some(tournament) = &mut self.tournament {
+            tournament.record(outcome);
+        }
+        self.current_screen = Screen::Tournament;
+    }
+
+    fn handle_tournament_input(&mut self, key: KeyCode) {
+        let Some(tournament) = &self.tournament else {
+            // still picking a playlist
+            match key {
+                KeyCode::Right | KeyCode::Char('l') => {
+                    self.selected_playlist_index =
+                        (self.selected_playlist_index + 1) % self.playlists.len().max(1);
+                }
+                KeyCode::Left | KeyCode::Char('h') => {
+                    self.selected_playlist_index = self.selected_playlist_index.saturating_sub(1);
+                }
+                KeyCode::Enter => {
+                    if let Some(playlist) = self.playlists.get(self.selected_playlist_index) {
+                        self.tournament = Some(Tournament::new(playlist.clone()));
+                    }
+                }
+                KeyCode::Backspace | KeyCode::Char('q') => self.leave_tournament(),
+                _ => {}
+            }
+            return;
+        };
+
+        match (&tournament.phase, key) {
+            (TournamentPhase::Intermission, KeyCode::Enter) => self.start_tournament_round(),
+            (TournamentPhase::Finished, KeyCode::Enter) => self.leave_tournament(),
+            (_, KeyCode::Backspace | KeyCode::Char('q')) => self.leave_tournament(),
+            _ => {}
+        }
+    }
+
+    fn start_tournament_round(&mut self) {
+        let Some(tournament) = &mut self.tournament else {
+            return;
+        };
+        let Some(step) = tournament.current_step().cloned() else {
+            return;
+        };
+        tournament.phase = TournamentPhase::Playing;
+        let same_step = tournament.round_in_step > 0;
+
+        // later rounds of the same step keep playing the same game, so hangman
+        // doesn't hand out a word twice
+        match &mut self.current_game {
+            Some(game) if same_step => game.restart(),
+            _ => self.current_game = Some(self.create_step_game(&step)),
+        }
+
+        self.current_game_type = Some(step.game);
+        self.current_screen = Screen::Game;
+    }
+
+    fn create_step_game(&self, step: &PlaylistStep) -> Box<dyn Game> {
+        match (step.game, &step.checkpoint) {
+            (GameType::Adventure, Some(checkpoint)) => {
+                let mut game = Box::new(Adventure::new());
+                game.restart();
+                game.checkpoint = Some(checkpoint.clone());
+                game
+            }
+            (GameType::Hangman, _) => {
+                let mut game = self.hangman_game(WordSelection::default());
+                game.tournament = true;
+                game.restart();
+                let (width, height) = self.terminal_size;
+                game.resize(width, height);
+                game
+            }
+            (game_type, _) => self.create_game(game_type),
+        }
+    }
+
+    fn leave_tournament(&mut self) {
+        self.sync_game_progress();
+        self.tournament = None;
+        self.current_game = None;
+        self.current_game_type = None;
+        self.current_screen = Screen::Dashboard;
+    }
+
     pub fn get_selected_game_name(&self) -> &str {
         if let Some(game_type) = self.available_games.get(self.selected_game_index) {
             game_type.name()
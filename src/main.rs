@@ -3,36 +3,76 @@ use crate::event::KeyEventKind;
 use hub::GameHub;
 use ratatui::Terminal;
 use ratatui::backend::Backend;
-use ratatui::crossterm::event::{self, DisableMouseCapture, EnableMouseCapture, Event};
+use ratatui::crossterm::event::{
+    self, DisableMouseCapture, EnableMouseCapture, Event, MouseButton, MouseEventKind,
+};
 use ratatui::crossterm::execute;
 use ratatui::crossterm::terminal::{
-    EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode,
+    self as crossterm_terminal, EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode,
+    enable_raw_mode,
 };
 use ratatui::prelude::CrosstermBackend;
 use std::error::Error;
 use std::io;
 use std::time::{Duration, Instant};
 use ui::render_ui;
+use utils::recorder::{CastFile, CastRecorder};
 
+mod cli;
 mod games;
+mod greetings;
 mod hub;
+mod profile;
+mod server;
+mod split;
+mod tournament;
 mod ui;
 mod utils;
-
+mod words;
 
 fn main() -> Result<(), Box<dyn Error>> {
+    let options = match cli::parse_args(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("{}", message);
+            std::process::exit(2);
+        }
+    };
+
+    if let Some(command) = options.words {
+        if !words::run(command)? {
+            std::process::exit(1);
+        }
+        return Ok(());
+    }
+
+    if let Some(addr) = &options.serve {
+        server::serve(addr)?;
+        return Ok(());
+    }
+
+    // Optionally record every frame the terminal draws to an asciinema cast
+    let cast = match &options.record {
+        Some(path) => {
+            let (width, height) = crossterm_terminal::size()?;
+            Some(CastFile::create(path, width, height)?)
+        }
+        None => None,
+    };
+
     enable_raw_mode()?;
     let mut stderr = io::stderr();
     execute!(stderr, EnterAlternateScreen, EnableMouseCapture)?;
-    let backend = CrosstermBackend::new(stderr);
+    let backend = CrosstermBackend::new(CastRecorder::new(stderr, cast));
     let mut terminal = Terminal::new(backend)?;
 
     // Initialize the game hub
     let mut game_hub = GameHub::new();
 
-    run_app(&mut terminal, &mut game_hub)?;
+    let result = run_app(&mut terminal, &mut game_hub);
+    game_hub.save_profile();
 
-    // Cleanup
+    // Cleanup, also when the loop ended with an error, so the terminal is usable again
     disable_raw_mode()?;
     execute!(
         terminal.backend_mut(),
@@ -41,30 +81,42 @@ fn main() -> Result<(), Box<dyn Error>> {
     )?;
     terminal.show_cursor()?;
 
+    result?;
     Ok(())
 }
 
-fn run_app<B: Backend>(terminal: &mut Terminal<B>, hub: &mut GameHub) -> Result<bool, io::Error> {
+fn run_app<B: Backend + io::Write>(
+    terminal: &mut Terminal<B>,
+    hub: &mut GameHub,
+) -> Result<bool, io::Error> {
     let tick_rate = Duration::from_millis(100);
     let mut last_tick = Instant::now();
 
+    let size = terminal.size()?;
+    hub.resize(size.width, size.height);
+
     loop {
-        terminal.draw(|f| render_ui(f, hub))?;
+        hub.send_clipboard(terminal.backend_mut());
+        let frame = terminal.draw(|f| render_ui(f, hub))?;
+        hub.save_requested_screenshot(frame.buffer);
 
         let timeout = tick_rate
             .checked_sub(last_tick.elapsed())
             .unwrap_or(Duration::from_secs(0));
 
         if event::poll(timeout)? {
-            if let Event::Key(key) = event::read()? {
-                if key.kind == KeyEventKind::Release {
-                    continue;
-                }
-
-                match key.code {
+            match event::read()? {
+                Event::Key(key) if key.kind != KeyEventKind::Release => match key.code {
                     KeyCode::Esc => return Ok(true),
+                    // F12 dumps the next frame as .txt, .ans, .html and .svg into screenshots/
+                    KeyCode::F(12) => hub.request_screenshot(),
                     _ => hub.handle_input(key.code),
+                },
+                Event::Mouse(mouse) if mouse.kind == MouseEventKind::Down(MouseButton::Left) => {
+                    hub.handle_click(mouse.column, mouse.row)
                 }
+                Event::Resize(width, height) => hub.resize(width, height),
+                _ => {}
             }
         }
 
@@ -0,0 +1,306 @@
+use crate::games::letters::LetterRules;
+use crate::games::{GameOutcome, HangmanRound};
+use crate::utils::time::{day_key, today};
+use chrono::{Local, NaiveDate};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::sync::{Arc, Mutex};
+
+const PROFILE_PATH: &str = "data/profile.json";
+// how many rounds the wrong guesses sparkline goes back
+const RECENT_ROUNDS: usize = 40;
+// high scores kept per pack
+const LEADERBOARD_SIZE: usize = 5;
+
+#[derive(Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    // 0 turns the reminder off
+    pub break_reminder_minutes: u64,
+    pub hangman_letters: LetterRules,
+    // name of the drawing in data/gallows.json
+    pub gallows: String,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            break_reminder_minutes: 45,
+            hangman_letters: LetterRules::default(),
+            gallows: "galg".to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct HighScore {
+    pub points: u32,
+    pub word: String,
+    pub day: String,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct HangmanStats {
+    // guesses at the whole word, right or wrong
+    pub solve_attempts: u32,
+    pub solves: u32,
+    pub rounds: u32,
+    pub wins: u32,
+    pub current_streak: u32,
+    pub best_streak: u32,
+    pub wrong_guesses: u32,
+    // wrong guesses per round, oldest first
+    pub recent_wrong_guesses: Vec<u32>,
+    pub missed_letters: BTreeMap<char, u32>,
+    pub lost_words: BTreeMap<String, u32>,
+    // pack -> its best rounds, highest first
+    pub leaderboards: BTreeMap<String, Vec<HighScore>>,
+}
+
+impl HangmanStats {
+    fn record(&mut self, won: bool, round: &HangmanRound) {
+        self.solve_attempts += round.solve_attempts;
+        self.solves += round.solved as u32;
+        self.rounds += 1;
+        self.wrong_guesses += round.wrong_guesses;
+
+        self.recent_wrong_guesses.push(round.wrong_guesses);
+        let overflow = self.recent_wrong_guesses.len().saturating_sub(RECENT_ROUNDS);
+        self.recent_wrong_guesses.drain(..overflow);
+
+        for letter in &round.missed_letters {
+            *self.missed_letters.entry(*letter).or_default() += 1;
+        }
+
+        if won {
+            self.wins += 1;
+            self.current_streak += 1;
+            self.best_streak = self.best_streak.max(self.current_streak);
+        } else {
+            self.current_streak = 0;
+            *self.lost_words.entry(round.word.clone()).or_default() += 1;
+        }
+
+        if round.points > 0 {
+            self.add_high_score(&round.pack, round.points, &round.word);
+        }
+    }
+
+    // A tie goes to whoever got there first
+    fn add_high_score(&mut self, pack: &str, points: u32, word: &str) {
+        let board = self.leaderboards.entry(pack.to_string()).or_default();
+        let place = board.iter().take_while(|score| score.points >= points).count();
+        if place >= LEADERBOARD_SIZE {
+            return;
+        }
+
+        let score = HighScore {
+            points,
+            word: word.to_string(),
+            day: day_key(today()),
+        };
+        board.insert(place, score);
+        board.truncate(LEADERBOARD_SIZE);
+    }
+
+    pub fn leaderboard(&self, pack: &str) -> &[HighScore] {
+        self.leaderboards.get(pack).map_or(&[], Vec::as_slice)
+    }
+
+    pub fn best_points(&self, pack: &str) -> u32 {
+        self.leaderboard(pack).first().map_or(0, |score| score.points)
+    }
+
+    pub fn win_rate(&self) -> f64 {
+        match self.rounds {
+            0 => 0.0,
+            rounds => self.wins as f64 / rounds as f64,
+        }
+    }
+
+    pub fn average_wrong_guesses(&self) -> f64 {
+        match self.rounds {
+            0 => 0.0,
+            rounds => self.wrong_guesses as f64 / rounds as f64,
+        }
+    }
+
+    // most often first, alphabetical when it's a tie
+    pub fn most_missed_letters(&self, amount: usize) -> Vec<(char, u32)> {
+        let mut letters: Vec<(char, u32)> =
+            self.missed_letters.iter().map(|(letter, count)| (*letter, *count)).collect();
+        letters.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+        letters.truncate(amount);
+        letters
+    }
+
+    pub fn most_lost_words(&self, amount: usize) -> Vec<(&str, u32)> {
+        let mut words: Vec<(&str, u32)> =
+            self.lost_words.iter().map(|(word, count)| (word.as_str(), *count)).collect();
+        words.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+        words.truncate(amount);
+        words
+    }
+}
+
+#[derive(Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct DailyResult {
+    pub day: String,
+    // the emoji summary, to show again when you come back that day
+    pub summary: String,
+}
+
+// Days in a row that one pack's word of the day got guessed
+#[derive(Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct DailyStreak {
+    pub current: u32,
+    pub best: u32,
+    pub last_won: Option<String>,
+}
+
+impl DailyStreak {
+    // The streak still counts if the last win was today or yesterday
+    pub fn days(&self, today: NaiveDate) -> u32 {
+        let yesterday = today.pred_opt().map(day_key);
+        match &self.last_won {
+            Some(day) if *day == day_key(today) || Some(day) == yesterday.as_ref() => {
+                self.current
+            }
+            _ => 0,
+        }
+    }
+
+    // What the streak becomes when today's word gets guessed
+    pub fn days_if_won(&self, today: NaiveDate) -> u32 {
+        match &self.last_won {
+            Some(day) if *day == day_key(today) => self.current,
+            _ => self.days(today) + 1,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct DailyStats {
+    // pack -> the last day its word of the day was started
+    pub played: BTreeMap<String, String>,
+    // pack -> how the last finished word of the day went
+    pub results: BTreeMap<String, DailyResult>,
+    // every pack has its own word of the day, so its own streak too
+    pub streaks: BTreeMap<String, DailyStreak>,
+}
+
+impl DailyStats {
+    pub fn played_on(&self, pack: &str, day: &str) -> bool {
+        self.played.get(pack).is_some_and(|played| played == day)
+    }
+
+    pub fn streak(&self, pack: &str, today: NaiveDate) -> u32 {
+        self.streaks.get(pack).map_or(0, |streak| streak.days(today))
+    }
+
+    pub fn streak_if_won(&self, pack: &str, today: NaiveDate) -> u32 {
+        self.streaks.get(pack).map_or(1, |streak| streak.days_if_won(today))
+    }
+
+    pub fn finished_on(&self, pack: &str, day: &str) -> bool {
+        self.results.get(pack).is_some_and(|result| result.day == day)
+    }
+
+    pub fn record(&mut self, today: NaiveDate, pack: &str, won: bool, summary: String) {
+        let streak = self.streaks.entry(pack.to_string()).or_default();
+        if won {
+            streak.current = streak.days_if_won(today);
+            streak.best = streak.best.max(streak.current);
+            streak.last_won = Some(day_key(today));
+        } else {
+            streak.current = 0;
+        }
+        let result = DailyResult {
+            day: day_key(today),
+            summary,
+        };
+        self.results.insert(pack.to_string(), result);
+    }
+}
+
+// One profile for every hub in the process, so telnet sessions don't save
+// over each other
+pub type SharedProfile = Arc<Mutex<Profile>>;
+
+// Everything that should survive a restart. Missing fields fall back to their
+// defaults, so older profile files keep loading when new fields are added.
+#[derive(Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct Profile {
+    pub settings: Settings,
+    // "YYYY-MM-DD" -> seconds played that day
+    pub daily_play_seconds: BTreeMap<String, u64>,
+    pub hangman: HangmanStats,
+    // word pack -> hangman words that already came up
+    pub hangman_history: BTreeMap<String, BTreeSet<String>>,
+    pub daily: DailyStats,
+    // set when the file on disk couldn't be read or moved aside, so it's left alone
+    #[serde(skip)]
+    pub read_only: bool,
+}
+
+impl Profile {
+    // No file yet is a fresh start. A file that can't be used is moved aside
+    // before anything gets saved over it, and what happened to it comes back
+    // with the fresh profile.
+    pub fn load() -> (Self, Option<String>) {
+        match fs::read(PROFILE_PATH) {
+            Ok(contents) => {
+                if let Ok(profile) = serde_json::from_slice(&contents) {
+                    return (profile, None);
+                }
+            }
+            Err(e) if e.kind() == ErrorKind::NotFound => return (Self::default(), None),
+            Err(_) => {}
+        }
+
+        let backup = format!("{}.kapot-{}", PROFILE_PATH, Local::now().format("%Y%m%d-%H%M%S"));
+        let mut profile = Self::default();
+        let message = match fs::rename(PROFILE_PATH, &backup) {
+            Ok(()) => format!("⚠️ profiel onleesbaar, bewaard als {}", backup),
+            Err(_) => {
+                profile.read_only = true;
+                "⚠️ profiel onleesbaar, er wordt niets opgeslagen".to_string()
+            }
+        };
+        (profile, Some(message))
+    }
+
+    pub fn shared(self) -> SharedProfile {
+        Arc::new(Mutex::new(self))
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        if self.read_only {
+            return Ok(());
+        }
+        let contents = serde_json::to_string_pretty(self)?;
+        fs::write(PROFILE_PATH, contents)
+    }
+
+    pub fn add_play_seconds(&mut self, day: &str, seconds: u64) {
+        *self.daily_play_seconds.entry(day.to_string()).or_default() += seconds;
+    }
+
+    pub fn record_round(&mut self, outcome: &GameOutcome) {
+        if let Some(round) = &outcome.hangman {
+            self.hangman.record(outcome.won, round);
+        }
+    }
+
+    pub fn play_seconds_on(&self, day: &str) -> u64 {
+        self.daily_play_seconds.get(day).copied().unwrap_or(0)
+    }
+}
@@ -0,0 +1,542 @@
+use crate::hub::GameHub;
+use crate::profile::{Profile, SharedProfile};
+use crate::ui::render_ui;
+use ratatui::crossterm::cursor::Hide;
+use ratatui::crossterm::event::KeyCode;
+use ratatui::crossterm::execute;
+use ratatui::crossterm::terminal::{EnterAlternateScreen, LeaveAlternateScreen};
+use ratatui::layout::Rect;
+use ratatui::prelude::CrosstermBackend;
+use ratatui::{Terminal, TerminalOptions, Viewport};
+use std::io::{self, BufWriter, ErrorKind, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::thread;
+use std::time::{Duration, Instant};
+
+// Telnet protocol bytes (RFC 854, 857, 858, 1073)
+const IAC: u8 = 255;
+const DONT: u8 = 254;
+const DO: u8 = 253;
+const WONT: u8 = 252;
+const WILL: u8 = 251;
+const SB: u8 = 250;
+const SE: u8 = 240;
+const ECHO: u8 = 1;
+const SUPPRESS_GO_AHEAD: u8 = 3;
+const NAWS: u8 = 31;
+
+const DEFAULT_SIZE: (u16, u16) = (80, 24);
+// how long the rest of a cut-off key gets to arrive, after that ESC is just Esc
+const ESCAPE_WAIT: Duration = Duration::from_millis(50);
+
+enum ClientEvent {
+    Key(KeyCode),
+    Resize(u16, u16),
+}
+
+pub fn serve(addr: &str) -> io::Result<()> {
+    let listener = TcpListener::bind(addr)?;
+    eprintln!("Susan's Game Hub luistert op {} (telnet)", listener.local_addr()?);
+    eprintln!("F12-screenshots van spelers komen hier in screenshots/");
+
+    // every session plays on the same profile, so they can't save over each other
+    let (profile, problem) = Profile::load();
+    if let Some(problem) = problem {
+        eprintln!("{}", problem);
+    }
+    let profile = profile.shared();
+
+    for stream in listener.incoming() {
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(e) => {
+                eprintln!("verbinding mislukt: {}", e);
+                continue;
+            }
+        };
+
+        let profile = profile.clone();
+        thread::spawn(move || {
+            let peer = stream
+                .peer_addr()
+                .map(|a| a.to_string())
+                .unwrap_or_else(|_| "?".to_string());
+            eprintln!("{} is binnen", peer);
+
+            if let Err(e) = handle_client(stream, profile) {
+                eprintln!("{}: {}", peer, e);
+            }
+            eprintln!("{} is weg", peer);
+        });
+    }
+
+    Ok(())
+}
+
+fn handle_client(stream: TcpStream, profile: SharedProfile) -> io::Result<()> {
+    let mut hub = GameHub::with_profile(profile);
+    let result = run_session(stream, &mut hub);
+    hub.save_profile();
+    result
+}
+
+// One telnet session on the given hub, until the client leaves or presses Esc
+fn run_session(stream: TcpStream, hub: &mut GameHub) -> io::Result<()> {
+    stream.set_nodelay(true)?;
+
+    // Character mode: we claim echoing (so the client stops echoing keys), no go-aheads,
+    // and ask the client to report its window size
+    let mut writer = BufWriter::new(stream.try_clone()?);
+    writer.write_all(&[
+        IAC,
+        WILL,
+        ECHO,
+        IAC,
+        WILL,
+        SUPPRESS_GO_AHEAD,
+        IAC,
+        DO,
+        SUPPRESS_GO_AHEAD,
+        IAC,
+        DO,
+        NAWS,
+    ])?;
+    execute!(writer, EnterAlternateScreen, Hide)?;
+
+    let (tx, rx) = mpsc::channel();
+    let reader = stream.try_clone()?;
+    thread::spawn(move || read_client(reader, tx));
+
+    let (width, height) = DEFAULT_SIZE;
+    let backend = CrosstermBackend::new(writer);
+    let mut terminal = Terminal::with_options(
+        backend,
+        TerminalOptions {
+            viewport: Viewport::Fixed(Rect::new(0, 0, width, height)),
+        },
+    )?;
+    terminal.clear()?;
+
+    hub.resize(width, height);
+    let result = run_client(&mut terminal, hub, rx);
+
+    let _ = terminal.show_cursor();
+    let _ = execute!(terminal.backend_mut(), LeaveAlternateScreen);
+    let _ = stream.shutdown(std::net::Shutdown::Both);
+
+    result
+}
+
+// Same loop as run_app in main, but fed by the socket instead of the local terminal
+fn run_client<W: Write>(
+    terminal: &mut Terminal<CrosstermBackend<W>>,
+    hub: &mut GameHub,
+    events: Receiver<ClientEvent>,
+) -> io::Result<()> {
+    let tick_rate = Duration::from_millis(100);
+    let mut last_tick = Instant::now();
+
+    loop {
+        hub.send_clipboard(terminal.backend_mut());
+        let frame = terminal.draw(|f| render_ui(f, hub))?;
+        hub.save_requested_screenshot(frame.buffer);
+
+        let timeout = tick_rate
+            .checked_sub(last_tick.elapsed())
+            .unwrap_or(Duration::from_secs(0));
+
+        match events.recv_timeout(timeout) {
+            Ok(ClientEvent::Key(KeyCode::Esc)) => return Ok(()),
+            // saved on the server, the player only gets to see the notice
+            Ok(ClientEvent::Key(KeyCode::F(12))) => hub.request_screenshot(),
+            Ok(ClientEvent::Key(key)) => hub.handle_input(key),
+            Ok(ClientEvent::Resize(width, height)) => {
+                terminal.resize(Rect::new(0, 0, width.max(1), height.max(1)))?;
+                hub.resize(width.max(1), height.max(1));
+            }
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => return Ok(()),
+        }
+
+        if last_tick.elapsed() >= tick_rate {
+            hub.update();
+            last_tick = Instant::now();
+        }
+    }
+}
+
+#[derive(Default)]
+enum TelnetState {
+    #[default]
+    Data,
+    Iac,
+    Negotiation,
+    Sub(Vec<u8>),
+    SubIac(Vec<u8>),
+}
+
+// Splits what the client sends into typed bytes and window sizes. The state
+// carries over between reads, so a command cut in two still gets parsed.
+#[derive(Default)]
+struct TelnetParser {
+    state: TelnetState,
+}
+
+impl TelnetParser {
+    fn feed(&mut self, bytes: &[u8]) -> (Vec<u8>, Vec<(u16, u16)>) {
+        let mut data = Vec::with_capacity(bytes.len());
+        let mut sizes = Vec::new();
+
+        for &byte in bytes {
+            self.state = match std::mem::take(&mut self.state) {
+                TelnetState::Data if byte == IAC => TelnetState::Iac,
+                TelnetState::Data => {
+                    data.push(byte);
+                    TelnetState::Data
+                }
+                TelnetState::Iac => match byte {
+                    IAC => {
+                        data.push(IAC);
+                        TelnetState::Data
+                    }
+                    WILL | WONT | DO | DONT => TelnetState::Negotiation,
+                    SB => TelnetState::Sub(Vec::new()),
+                    _ => TelnetState::Data,
+                },
+                TelnetState::Negotiation => TelnetState::Data,
+                TelnetState::Sub(sub) if byte == IAC => TelnetState::SubIac(sub),
+                TelnetState::Sub(mut sub) => {
+                    sub.push(byte);
+                    TelnetState::Sub(sub)
+                }
+                TelnetState::SubIac(sub) if byte == SE => {
+                    if let [NAWS, w1, w2, h1, h2, ..] = sub[..] {
+                        sizes.push((u16::from_be_bytes([w1, w2]), u16::from_be_bytes([h1, h2])));
+                    }
+                    TelnetState::Data
+                }
+                TelnetState::SubIac(mut sub) => {
+                    // IAC IAC inside a subnegotiation is an escaped 255
+                    sub.push(byte);
+                    TelnetState::Sub(sub)
+                }
+            };
+        }
+
+        (data, sizes)
+    }
+}
+
+fn read_client(mut stream: TcpStream, tx: Sender<ClientEvent>) {
+    let mut telnet = TelnetParser::default();
+    let mut keys = KeyDecoder::default();
+    let mut buf = [0u8; 1024];
+
+    loop {
+        // something cut off, like a lone ESC, only waits a moment for the rest
+        let waiting = keys.is_waiting();
+        if stream.set_read_timeout(waiting.then_some(ESCAPE_WAIT)).is_err() {
+            return;
+        }
+
+        let mut events = Vec::new();
+        match stream.read(&mut buf) {
+            Ok(0) => return,
+            Ok(n) => {
+                let (data, sizes) = telnet.feed(&buf[..n]);
+                events.extend(sizes.into_iter().map(|(w, h)| ClientEvent::Resize(w, h)));
+                events.extend(keys.feed(&data).into_iter().map(ClientEvent::Key));
+            }
+            Err(e) if waiting && matches!(e.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {
+                events.extend(keys.flush().into_iter().map(ClientEvent::Key));
+            }
+            Err(_) => return,
+        }
+
+        for event in events {
+            if tx.send(event).is_err() {
+                return;
+            }
+        }
+    }
+}
+
+// Turns the raw bytes a terminal sends into key codes. Only the sequences
+// the games actually use are recognised; everything else is dropped. A
+// sequence cut off at the end of a read is kept until the next one.
+#[derive(Default)]
+struct KeyDecoder {
+    pending: Vec<u8>,
+}
+
+impl KeyDecoder {
+    fn feed(&mut self, bytes: &[u8]) -> Vec<KeyCode> {
+        self.pending.extend_from_slice(bytes);
+        let (keys, used) = decode_keys(&self.pending, false);
+        self.pending.drain(..used);
+        keys
+    }
+
+    // Nothing more is coming, so what's left is the whole key, like Esc on its own
+    fn flush(&mut self) -> Vec<KeyCode> {
+        let (keys, _) = decode_keys(&self.pending, true);
+        self.pending.clear();
+        keys
+    }
+
+    fn is_waiting(&self) -> bool {
+        !self.pending.is_empty()
+    }
+}
+
+enum Decoded {
+    Key(KeyCode, usize),
+    Skip(usize),
+    // the start of something longer, wait for the rest
+    Incomplete,
+}
+
+// The keys in bytes and how many bytes they took. With `complete` false a
+// cut-off sequence at the end is left for later.
+fn decode_keys(bytes: &[u8], complete: bool) -> (Vec<KeyCode>, usize) {
+    let mut keys = Vec::new();
+    let mut used = 0;
+
+    while used < bytes.len() {
+        match decode_key(&bytes[used..], complete) {
+            Decoded::Key(key, len) => {
+                keys.push(key);
+                used += len;
+            }
+            Decoded::Skip(len) => used += len,
+            Decoded::Incomplete => break,
+        }
+    }
+
+    (keys, used)
+}
+
+fn decode_key(bytes: &[u8], complete: bool) -> Decoded {
+    // what the rest of the bytes mean if nothing more is coming
+    let cut_off = |key: Option<KeyCode>| match (complete, key) {
+        (false, _) => Decoded::Incomplete,
+        (true, Some(key)) => Decoded::Key(key, bytes.len()),
+        (true, None) => Decoded::Skip(bytes.len()),
+    };
+
+    match bytes {
+        // telnet sends CR NUL or CR LF for Enter
+        [b'\r', b'\n' | 0, ..] => Decoded::Key(KeyCode::Enter, 2),
+        [b'\r'] => cut_off(Some(KeyCode::Enter)),
+        [b'\r' | b'\n', ..] => Decoded::Key(KeyCode::Enter, 1),
+        [b'\t', ..] => Decoded::Key(KeyCode::Tab, 1),
+        [0x7f | 0x08, ..] => Decoded::Key(KeyCode::Backspace, 1),
+        [0x1b] => cut_off(Some(KeyCode::Esc)),
+        [0x1b, b'[', rest @ ..] => {
+            match rest.iter().position(|b| b.is_ascii_alphabetic() || *b == b'~') {
+                Some(end) => {
+                    let sequence = String::from_utf8_lossy(&rest[..=end]);
+                    match csi_key(&sequence) {
+                        Some(key) => Decoded::Key(key, end + 3),
+                        None => Decoded::Skip(end + 3),
+                    }
+                }
+                None => cut_off(None),
+            }
+        }
+        [0x1b, b'O'] => cut_off(None),
+        [0x1b, b'O', c, ..] => match ss3_key(*c) {
+            Some(key) => Decoded::Key(key, 3),
+            None => Decoded::Skip(3),
+        },
+        // a lone ESC, followed by an unrelated key
+        [0x1b, ..] => Decoded::Key(KeyCode::Esc, 1),
+        _ => decode_char(bytes, cut_off),
+    }
+}
+
+fn decode_char(bytes: &[u8], cut_off: impl Fn(Option<KeyCode>) -> Decoded) -> Decoded {
+    // the length of a UTF-8 character, from its first byte
+    let len = match bytes[0] {
+        0xf0.. => 4,
+        0xe0.. => 3,
+        0xc0.. => 2,
+        _ => 1,
+    };
+    if bytes.len() < len {
+        return cut_off(None);
+    }
+
+    match std::str::from_utf8(&bytes[..len]).ok().and_then(|c| c.chars().next()) {
+        Some(c) if c.is_control() => Decoded::Skip(len),
+        Some(c) => Decoded::Key(KeyCode::Char(c), len),
+        None => Decoded::Skip(1),
+    }
+}
+
+fn csi_key(sequence: &str) -> Option<KeyCode> {
+    let key = match sequence {
+        "A" => KeyCode::Up,
+        "B" => KeyCode::Down,
+        "C" => KeyCode::Right,
+        "D" => KeyCode::Left,
+        "H" | "1~" => KeyCode::Home,
+        "F" | "4~" => KeyCode::End,
+        "3~" => KeyCode::Delete,
+        "5~" => KeyCode::PageUp,
+        "6~" => KeyCode::PageDown,
+        "15~" => KeyCode::F(5),
+        "17~" => KeyCode::F(6),
+        "18~" => KeyCode::F(7),
+        "19~" => KeyCode::F(8),
+        "20~" => KeyCode::F(9),
+        "21~" => KeyCode::F(10),
+        "23~" => KeyCode::F(11),
+        "24~" => KeyCode::F(12),
+        _ => return None,
+    };
+
+    Some(key)
+}
+
+fn ss3_key(c: u8) -> Option<KeyCode> {
+    let key = match c {
+        b'A' => KeyCode::Up,
+        b'B' => KeyCode::Down,
+        b'C' => KeyCode::Right,
+        b'D' => KeyCode::Left,
+        b'P' => KeyCode::F(1),
+        b'Q' => KeyCode::F(2),
+        b'R' => KeyCode::F(3),
+        b'S' => KeyCode::F(4),
+        _ => return None,
+    };
+
+    Some(key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys(bytes: &[u8]) -> Vec<KeyCode> {
+        decode_keys(bytes, true).0
+    }
+
+    #[test]
+    fn decodes_keys() {
+        assert_eq!(keys(b"ab"), [KeyCode::Char('a'), KeyCode::Char('b')]);
+        assert_eq!(keys("é".as_bytes()), [KeyCode::Char('é')]);
+        assert_eq!(keys(b"\x1b[A\x1bOD"), [KeyCode::Up, KeyCode::Left]);
+        assert_eq!(keys(b"\x1b[24~\x1b[15~"), [KeyCode::F(12), KeyCode::F(5)]);
+        assert_eq!(keys(b"\x7f\t"), [KeyCode::Backspace, KeyCode::Tab]);
+        assert_eq!(keys(b"\x1bq"), [KeyCode::Esc, KeyCode::Char('q')]);
+        // unknown sequences and control characters are dropped
+        assert_eq!(keys(b"\x1b[99Zx\x01"), [KeyCode::Char('x')]);
+    }
+
+    #[test]
+    fn enter_is_one_key_whatever_follows_cr() {
+        assert_eq!(keys(b"\r\0a"), [KeyCode::Enter, KeyCode::Char('a')]);
+        assert_eq!(keys(b"\r\na"), [KeyCode::Enter, KeyCode::Char('a')]);
+        assert_eq!(keys(b"\ra"), [KeyCode::Enter, KeyCode::Char('a')]);
+        assert_eq!(keys(b"\n"), [KeyCode::Enter]);
+    }
+
+    #[test]
+    fn keeps_cut_off_sequences_for_the_next_read() {
+        let mut decoder = KeyDecoder::default();
+        assert!(decoder.feed(b"x\x1b").contains(&KeyCode::Char('x')));
+        assert!(decoder.is_waiting());
+        assert_eq!(decoder.feed(b"["), []);
+        assert_eq!(decoder.feed(b"C"), [KeyCode::Right]);
+        assert!(!decoder.is_waiting());
+
+        // CR NUL in two reads is still one Enter
+        assert_eq!(decoder.feed(b"\r"), []);
+        assert_eq!(decoder.feed(b"\0"), [KeyCode::Enter]);
+
+        let e = "é".as_bytes();
+        assert_eq!(decoder.feed(&e[..1]), []);
+        assert_eq!(decoder.feed(&e[1..]), [KeyCode::Char('é')]);
+    }
+
+    #[test]
+    fn lone_escape_counts_once_nothing_follows() {
+        let mut decoder = KeyDecoder::default();
+        assert_eq!(decoder.feed(b"\x1b"), []);
+        assert_eq!(decoder.flush(), [KeyCode::Esc]);
+        assert!(!decoder.is_waiting());
+    }
+
+    #[test]
+    fn telnet_commands_are_not_typed() {
+        let mut telnet = TelnetParser::default();
+        let (data, sizes) = telnet.feed(&[b'a', IAC, WILL, NAWS, IAC, DO, ECHO, b'b']);
+        assert_eq!(data, b"ab");
+        assert!(sizes.is_empty());
+
+        // IAC IAC is a 255 that was typed
+        assert_eq!(telnet.feed(&[IAC, IAC, b'c']).0, [255, b'c']);
+    }
+
+    #[test]
+    fn telnet_window_size() {
+        let mut telnet = TelnetParser::default();
+        let (data, sizes) = telnet.feed(&[IAC, SB, NAWS, 0, 100, 0, 40, IAC, SE, b'x']);
+        assert_eq!(data, b"x");
+        assert_eq!(sizes, [(100, 40)]);
+
+        // cut in the middle, and with a width of 255 escaped as IAC IAC
+        assert_eq!(telnet.feed(&[IAC, SB, NAWS, 0, IAC]), (vec![], vec![]));
+        assert_eq!(telnet.feed(&[IAC, 0, 30, IAC, SE]), (vec![], vec![(255, 30)]));
+    }
+
+    // Reads until the text shows up, the server hangs up or it takes too long
+    fn read_until(client: &mut TcpStream, text: &str) -> Vec<u8> {
+        client.set_read_timeout(Some(Duration::from_millis(100))).unwrap();
+        let deadline = Instant::now() + Duration::from_secs(5);
+        let mut received = Vec::new();
+        let mut buf = [0u8; 4096];
+
+        while Instant::now() < deadline {
+            match client.read(&mut buf) {
+                Ok(0) => break,
+                Ok(n) => received.extend_from_slice(&buf[..n]),
+                Err(_) => {}
+            }
+            if String::from_utf8_lossy(&received).contains(text) {
+                break;
+            }
+        }
+        received
+    }
+
+    #[test]
+    fn loopback_session() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        // a fresh profile that is never saved, the session only plays
+        let server = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            run_session(stream, &mut GameHub::with_profile(Profile::default().shared()))
+        });
+
+        let mut client = TcpStream::connect(addr).unwrap();
+        let greeting = read_until(&mut client, "spelletjes");
+        assert!(greeting.starts_with(&[IAC, WILL, ECHO]));
+        assert!(String::from_utf8_lossy(&greeting).contains("spelletjes"));
+
+        // a bigger window and an arrow key, split over two writes
+        client.write_all(&[IAC, SB, NAWS, 0, 100, 0, 30, IAC, SE, 0x1b]).unwrap();
+        thread::sleep(Duration::from_millis(10));
+        client.write_all(b"[C").unwrap();
+        let frame = read_until(&mut client, "slangetje");
+        assert!(String::from_utf8_lossy(&frame).contains("slangetje"));
+
+        // a lone Esc ends the session
+        client.write_all(b"\x1b").unwrap();
+        server.join().unwrap().unwrap();
+    }
+}
@@ -0,0 +1,203 @@
+use crate::games::hangman::HangmanGame;
+use crate::games::snake::SnakeGame;
+use crate::games::{Game, GameType};
+use crate::profile::Profile;
+use ratatui::crossterm::event::KeyCode;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Side {
+    Left,
+    Right,
+}
+
+impl Side {
+    pub const BOTH: [Side; 2] = [Side::Left, Side::Right];
+
+    fn index(self) -> usize {
+        match self {
+            Side::Left => 0,
+            Side::Right => 1,
+        }
+    }
+}
+
+// Only games that make sense as a race can be split
+pub enum SplitGames {
+    Hangman(Box<[HangmanGame; 2]>),
+    Snake(Box<[SnakeGame; 2]>),
+}
+
+pub enum RaceResult {
+    Winner(Side),
+    Draw,
+}
+
+pub struct SplitScreen {
+    pub games: SplitGames,
+    pub result: Option<RaceResult>,
+    finish_order: Vec<Side>,
+}
+
+impl SplitScreen {
+    // The hub sets the games up, so hangman gets your letter rules, drawing and history
+    pub fn new(games: SplitGames) -> Self {
+        let mut split = Self {
+            games,
+            result: None,
+            finish_order: Vec::new(),
+        };
+        split.restart();
+        split
+    }
+
+    pub fn game_type(&self) -> GameType {
+        match self.games {
+            SplitGames::Hangman(_) => GameType::Hangman,
+            SplitGames::Snake(_) => GameType::Snake,
+        }
+    }
+
+    pub fn game(&self, side: Side) -> &dyn Game {
+        match &self.games {
+            SplitGames::Hangman(games) => &games[side.index()],
+            SplitGames::Snake(games) => &games[side.index()],
+        }
+    }
+
+    fn game_mut(&mut self, side: Side) -> &mut dyn Game {
+        match &mut self.games {
+            SplitGames::Hangman(games) => &mut games[side.index()],
+            SplitGames::Snake(games) => &mut games[side.index()],
+        }
+    }
+
+    pub fn restart(&mut self) {
+        match &mut self.games {
+            // both players race on the same word
+            SplitGames::Hangman(games) => {
+                let [left, right] = games.as_mut();
+                left.start_new_game();
+                right.start_with_word(left.word_to_guess.clone());
+                right.pack_name = left.pack_name.clone();
+                right.difficulty = left.difficulty;
+                right.hint = left.hint.clone();
+                right.definition = left.definition.clone();
+                right.attribution = left.attribution.clone();
+            }
+            SplitGames::Snake(games) => games.iter_mut().for_each(|game| game.restart()),
+        }
+
+        self.result = None;
+        self.finish_order.clear();
+    }
+
+    pub fn save_progress(&self, profile: &mut Profile) {
+        for side in Side::BOTH {
+            self.game(side).save_progress(profile);
+        }
+    }
+
+    // Each game gets half the width, below the one-row header
+    pub fn resize(&mut self, width: u16, height: u16) {
+        for side in Side::BOTH {
+            self.game_mut(side).resize(width / 2, height.saturating_sub(1));
+        }
+    }
+
+    pub fn min_size(&self) -> (u16, u16) {
+        let (width, height) = Side::BOTH
+            .map(|side| self.game(side).min_size())
+            .into_iter()
+            .fold((0, 0), |(w, h), (gw, gh)| (w.max(gw), h.max(gh)));
+        (width * 2, height + 1)
+    }
+
+    pub fn keymap_hint(&self, side: Side) -> &'static str {
+        match (&self.games, side) {
+            (SplitGames::Hangman(_), Side::Left) => "kleine letters",
+            (SplitGames::Hangman(_), Side::Right) => "Shift + letters",
+            (SplitGames::Snake(_), Side::Left) => "W A S D",
+            (SplitGames::Snake(_), Side::Right) => "pijltjes",
+        }
+    }
+
+    // Works out whose key it is, and translates it into the key that game expects
+    fn route(&self, key: KeyCode) -> Option<(Side, KeyCode)> {
+        match (&self.games, key) {
+            (SplitGames::Hangman(_), KeyCode::Char(c)) if c.is_lowercase() => {
+                Some((Side::Left, key))
+            }
+            (SplitGames::Hangman(_), KeyCode::Char(c)) if c.is_uppercase() => {
+                Some((Side::Right, KeyCode::Char(c.to_lowercase().next().unwrap_or(c))))
+            }
+            (SplitGames::Snake(_), KeyCode::Char('w' | 'W')) => Some((Side::Left, KeyCode::Up)),
+            (SplitGames::Snake(_), KeyCode::Char('a' | 'A')) => Some((Side::Left, KeyCode::Left)),
+            (SplitGames::Snake(_), KeyCode::Char('s' | 'S')) => Some((Side::Left, KeyCode::Down)),
+            (SplitGames::Snake(_), KeyCode::Char('d' | 'D')) => Some((Side::Left, KeyCode::Right)),
+            (SplitGames::Snake(_), KeyCode::Up | KeyCode::Down | KeyCode::Left | KeyCode::Right) => {
+                Some((Side::Right, key))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn handle_input(&mut self, key: KeyCode) {
+        if self.result.is_some() {
+            return;
+        }
+
+        if let Some((side, key)) = self.route(key) {
+            // a finished side keeps its end screen until the race is over
+            if self.game(side).outcome().is_none() {
+                self.game_mut(side).handle_input(key);
+            }
+        }
+
+        self.update_result();
+    }
+
+    pub fn tick(&mut self) {
+        if self.result.is_some() {
+            return;
+        }
+
+        for side in Side::BOTH {
+            self.game_mut(side).tick();
+        }
+
+        self.update_result();
+    }
+
+    fn update_result(&mut self) {
+        for side in Side::BOTH {
+            if self.game(side).outcome().is_some() && !self.finish_order.contains(&side) {
+                self.finish_order.push(side);
+            }
+        }
+
+        let won = |side: Side| self.game(side).outcome().is_some_and(|o| o.won);
+        let score = |side: Side| self.game(side).outcome().map_or(0, |o| o.score);
+        let both_finished = self.finish_order.len() == 2;
+
+        self.result = match self.games {
+            // first one to guess the word wins
+            SplitGames::Hangman(_) => match self.finish_order.iter().find(|side| won(**side)) {
+                Some(side) => Some(RaceResult::Winner(*side)),
+                None if both_finished => Some(RaceResult::Draw),
+                None => None,
+            },
+            // most food wins, on a tie whoever stayed alive longest
+            SplitGames::Snake(_) if both_finished => {
+                let (left, right) = (score(Side::Left), score(Side::Right));
+                Some(if left > right {
+                    RaceResult::Winner(Side::Left)
+                } else if right > left {
+                    RaceResult::Winner(Side::Right)
+                } else {
+                    RaceResult::Winner(self.finish_order[1])
+                })
+            }
+            SplitGames::Snake(_) => None,
+        };
+    }
+}
@@ -0,0 +1,134 @@
+use crate::games::{GameOutcome, GameType};
+use serde::Deserialize;
+
+const PLAYLISTS_PATH: &str = "data/playlists.json";
+
+fn one_round() -> u32 {
+    1
+}
+
+#[derive(Deserialize, Clone)]
+pub struct PlaylistStep {
+    pub game: GameType,
+    #[serde(default = "one_round")]
+    pub rounds: u32,
+    // adventure only: the scene id that ends the round
+    pub checkpoint: Option<String>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct Playlist {
+    pub name: String,
+    #[serde(default)]
+    pub players: Vec<String>,
+    pub steps: Vec<PlaylistStep>,
+}
+
+#[derive(Deserialize)]
+struct PlaylistsJsonRoot {
+    playlists: Vec<Playlist>,
+}
+
+pub fn load_playlists() -> Vec<Playlist> {
+    std::fs::read_to_string(PLAYLISTS_PATH)
+        .ok()
+        .and_then(|file| serde_json::from_str::<PlaylistsJsonRoot>(&file).ok())
+        .map(|root| root.playlists)
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|playlist| !playlist.steps.is_empty())
+        .collect()
+}
+
+pub struct RoundResult {
+    pub player: usize,
+    pub game: GameType,
+    pub won: bool,
+    pub score: u32,
+}
+
+#[derive(PartialEq)]
+pub enum TournamentPhase {
+    Intermission,
+    Playing,
+    Finished,
+}
+
+pub struct Tournament {
+    pub playlist: Playlist,
+    pub phase: TournamentPhase,
+    pub step_index: usize,
+    pub round_in_step: u32,
+    pub scores: Vec<u32>,
+    pub results: Vec<RoundResult>,
+}
+
+impl Tournament {
+    pub fn new(mut playlist: Playlist) -> Self {
+        if playlist.players.is_empty() {
+            playlist.players.push("Susan".to_string());
+        }
+
+        Self {
+            scores: vec![0; playlist.players.len()],
+            playlist,
+            phase: TournamentPhase::Intermission,
+            step_index: 0,
+            round_in_step: 0,
+            results: Vec::new(),
+        }
+    }
+
+    pub fn current_step(&self) -> Option<&PlaylistStep> {
+        self.playlist.steps.get(self.step_index)
+    }
+
+    pub fn total_rounds(&self) -> u32 {
+        self.playlist.steps.iter().map(|s| s.rounds.max(1)).sum()
+    }
+
+    // players take turns, one round each
+    pub fn current_player(&self) -> usize {
+        self.results.len() % self.playlist.players.len()
+    }
+
+    pub fn player_name(&self, player: usize) -> &str {
+        &self.playlist.players[player]
+    }
+
+    pub fn record(&mut self, outcome: GameOutcome) {
+        let Some(step) = self.current_step() else {
+            return;
+        };
+        let game = step.game;
+        let rounds = step.rounds.max(1);
+        let player = self.current_player();
+
+        self.scores[player] += outcome.score;
+        self.results.push(RoundResult {
+            player,
+            game,
+            won: outcome.won,
+            score: outcome.score,
+        });
+
+        self.round_in_step += 1;
+        if self.round_in_step >= rounds {
+            self.step_index += 1;
+            self.round_in_step = 0;
+        }
+
+        self.phase = if self.step_index >= self.playlist.steps.len() {
+            TournamentPhase::Finished
+        } else {
+            TournamentPhase::Intermission
+        };
+    }
+
+    // players sorted by score, best first
+    pub fn standings(&self) -> Vec<(usize, u32)> {
+        let mut standings: Vec<(usize, u32)> = self.scores.iter().copied().enumerate().collect();
+        standings.sort_by_key(|(_, score)| std::cmp::Reverse(*score));
+        standings
+    }
+}
@@ -1,4 +1,5 @@
 use crate::games::adventure::Adventure;
+use crate::ui::COMPACT_WIDTH;
 use ratatui::prelude::*;
 use ratatui::style::{Color, Style};
 use ratatui::text::Span;
@@ -11,9 +12,14 @@ pub fn render_adventure_game(game: &Adventure, frame: &mut Frame, area: Rect) {
         .constraints([Constraint::Min(3), Constraint::Length(3)])
         .split(area);
 
+    let compact = area.width < COMPACT_WIDTH;
     let top_split = Layout::default()
         .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+        .constraints(if compact {
+            [Constraint::Percentage(100), Constraint::Percentage(0)]
+        } else {
+            [Constraint::Percentage(60), Constraint::Percentage(40)]
+        })
         .split(main_layout[0]);
 
     // === LEFT PANEL (Log Window) with scrolling ===
@@ -51,6 +57,17 @@ pub fn render_adventure_game(game: &Adventure, frame: &mut Frame, area: Rect) {
 
     frame.render_widget(log_widget, top_split[0]);
 
+    // narrow terminals only get the log, the side panel would be unreadable
+    if !compact {
+        render_side_panel(game, frame, top_split[1]);
+    }
+
+    // === Bottom Input Line with autocomplete ===
+    let input_widget = render_input_line(game);
+    frame.render_widget(input_widget, main_layout[1]);
+}
+
+fn render_side_panel(game: &Adventure, frame: &mut Frame, area: Rect) {
     // === RIGHT PANEL ===
     let right_split = Layout::default()
         .direction(Direction::Vertical)
@@ -59,7 +76,7 @@ pub fn render_adventure_game(game: &Adventure, frame: &mut Frame, area: Rect) {
             Constraint::Min(5),
             Constraint::Length(5),
         ])
-        .split(top_split[1]);
+        .split(area);
 
     let inventory_items = game
         .inventory()
@@ -99,10 +116,6 @@ pub fn render_adventure_game(game: &Adventure, frame: &mut Frame, area: Rect) {
         Paragraph::new(stats_lines).block(Block::default().borders(Borders::ALL).title("Stats"));
 
     frame.render_widget(stats_widget, right_split[2]);
-
-    // === Bottom Input Line with autocomplete ===
-    let input_widget = render_input_line(game);
-    frame.render_widget(input_widget, main_layout[1]);
 }
 
 fn render_input_line(game: &Adventure) -> Paragraph<'_> {
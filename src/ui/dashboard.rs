@@ -1,38 +1,54 @@
 use crate::hub::GameHub;
+use crate::ui::COMPACT_WIDTH;
+use crate::utils::time::{format_duration, today};
 use colorgrad::GradientBuilder;
 use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Paragraph, Tabs};
+use std::time::{SystemTime, UNIX_EPOCH};
 use tui_big_text::{BigText, PixelSize};
 use tui_gradient_block::gradient_block::GradientBlock;
 use tui_gradient_block::types::G;
 
 pub fn render_dashboard(frame: &mut Frame, hub: &GameHub) {
-    let block = get_gradient_block("💖 Susan's Game Hub 💖");
+    let theme = hub.greetings.theme_for(today());
+    let [r, g, b] = theme.color;
+
+    let block = get_gradient_block(&theme.subtitle);
     frame.render_widget(block, frame.area());
 
+    // the big title needs room, small terminals get it as plain text
+    let compact = frame.area().width < COMPACT_WIDTH || frame.area().height < 30;
+
     let main_chunks = Layout::default()
         .direction(Direction::Vertical)
-        .margin(2)
+        .margin(if compact { 1 } else { 2 })
         .constraints([
-            Constraint::Length(8), // Big title
-            Constraint::Min(1),    // Game selection area
-            Constraint::Length(3), // Instructions
+            Constraint::Length(if compact { 1 } else { 5 }), // Big title
+            Constraint::Length(3),                           // Message of the day
+            Constraint::Min(1),                              // Game selection area
+            Constraint::Length(if compact { 7 } else { 4 }), // Instructions
         ])
         .split(frame.area());
 
-    let big_title_chunk_centered = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(43), Constraint::Percentage(50)])
-        .split(main_chunks[0]);
-
-    // Big title using tui-big-text
-    let big_title = BigText::builder()
-        .pixel_size(PixelSize::Quadrant)
-        .style(Style::default().fg(Color::Rgb(255, 105, 180)).bold())
-        .lines(vec!["PIPIS GAME HUB".into()])
-        .build();
+    let title_style = Style::default().fg(Color::Rgb(r, g, b)).bold();
+    if compact {
+        frame.render_widget(
+            Paragraph::new(Line::from(theme.title.as_str()).style(title_style)).centered(),
+            main_chunks[0],
+        );
+    } else {
+        // Big title using tui-big-text
+        let big_title = BigText::builder()
+            .pixel_size(PixelSize::Quadrant)
+            .style(title_style)
+            .lines(vec![theme.title.as_str().into()])
+            .centered()
+            .build();
+
+        frame.render_widget(big_title, main_chunks[0]);
+    }
 
-    frame.render_widget(big_title, big_title_chunk_centered[1]);
+    render_message(frame, hub, main_chunks[1]);
 
     // Game selection area
     let game_area = Layout::default()
@@ -41,7 +57,7 @@ pub fn render_dashboard(frame: &mut Frame, hub: &GameHub) {
             Constraint::Length(5), // Game tabs
             Constraint::Min(1),    // Game description
         ])
-        .split(main_chunks[1]);
+        .split(main_chunks[2]);
 
     // Create tabs for games
     let game_names: Vec<String> = hub
@@ -97,6 +113,12 @@ pub fn render_dashboard(frame: &mut Frame, hub: &GameHub) {
         ),
         Span::styled("Enter ", Style::default().fg(Color::Green).bold()),
         Span::styled("om te spelen • ", Style::default().fg(Color::Gray)),
+        Span::styled("S ", Style::default().fg(Color::Cyan).bold()),
+        Span::styled("voor je stats • ", Style::default().fg(Color::Gray)),
+        Span::styled("V ", Style::default().fg(Color::Rgb(255, 179, 186)).bold()),
+        Span::styled("voor samen racen • ", Style::default().fg(Color::Gray)),
+        Span::styled("T ", Style::default().fg(Color::Rgb(255, 223, 186)).bold()),
+        Span::styled("voor een toernooi • ", Style::default().fg(Color::Gray)),
         Span::styled("ESC ", Style::default().fg(Color::Red).bold()),
         Span::styled("om te stoppen", Style::default().fg(Color::Gray)),
     ])];
@@ -109,11 +131,59 @@ pub fn render_dashboard(frame: &mut Frame, hub: &GameHub) {
                 .title(
                     Line::from("🎯 Controls 🎯")
                         .style(Style::default().fg(Color::Rgb(186, 255, 201)).bold()),
+                )
+                .title_bottom(get_play_time_line(hub)),
+        )
+        .alignment(Alignment::Center)
+        .wrap(ratatui::widgets::Wrap { trim: true });
+
+    frame.render_widget(instructions_widget, main_chunks[3]);
+}
+
+fn render_message(frame: &mut Frame, hub: &GameHub, area: Rect) {
+    let unix_seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    let text = hub
+        .greetings
+        .message_for(today(), unix_seconds)
+        .map_or("", |message| message.text.as_str());
+
+    let message = Paragraph::new(Line::from(text))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_set(symbols::border::ROUNDED)
+                .title(
+                    Line::from("💌 berichtje van vandaag 💌")
+                        .style(Style::default().fg(Color::Rgb(255, 179, 186)).bold()),
                 ),
         )
-        .alignment(Alignment::Center);
+        .alignment(Alignment::Center)
+        .style(Style::default().fg(Color::White).italic());
+
+    frame.render_widget(message, area);
+}
+
+fn get_play_time_line(hub: &GameHub) -> Line<'static> {
+    let mut text = format!(
+        " ⏱️ vandaag {} • deze sessie {}",
+        format_duration(hub.played_today()),
+        format_duration(hub.play_time.session)
+    );
+
+    if let Some(played) = hub.play_time.per_game.get(hub.get_selected_game_name()) {
+        text.push_str(&format!(
+            " • {} {}",
+            hub.get_selected_game_name(),
+            format_duration(*played)
+        ));
+    }
+    text.push(' ');
 
-    frame.render_widget(instructions_widget, main_chunks[2]);
+    Line::from(text)
+        .style(Style::default().fg(Color::Rgb(255, 223, 186)))
+        .right_aligned()
 }
 
 fn pastel(col: (u8, u8, u8)) -> G {
@@ -125,7 +195,7 @@ fn pastel(col: (u8, u8, u8)) -> G {
     )
 }
 
-fn get_game_description(game_name: &str) -> Vec<Line> {
+fn get_game_description(game_name: &str) -> Vec<Line<'_>> {
     match game_name {
         "Galgje" => vec![
             Line::from("🎯 pipis galgje spel!"),
@@ -148,7 +218,7 @@ fn get_game_description(game_name: &str) -> Vec<Line> {
     }
 }
 
-fn get_gradient_block(title_text: &str) -> GradientBlock<'_> {
+pub fn get_gradient_block(title_text: &str) -> GradientBlock<'_> {
     GradientBlock::new()
         .left_gradient(pastel((255, 179, 186))) // pastel pink
         .bottom_gradient(pastel((186, 225, 255))) // pastel blue
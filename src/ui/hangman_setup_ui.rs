@@ -0,0 +1,204 @@
+use crate::games::timer::TimeMode;
+use crate::games::word_packs::{Difficulty, WordPack, ALL_PACKS, ALL_PHRASES};
+use crate::hub::GameHub;
+use crate::ui::dashboard::get_gradient_block;
+use crate::utils::time::{day_key, today};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Paragraph, Tabs, Wrap};
+
+pub fn render_hangman_setup(frame: &mut Frame, hub: &GameHub) {
+    let block = get_gradient_block("🎯 Pipis galgje: kies je woordjes 🎯");
+    frame.render_widget(block, frame.area());
+
+    let area = frame.area().inner(Margin {
+        horizontal: 2,
+        vertical: 2,
+    });
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // packs
+            Constraint::Length(3), // difficulty
+            Constraint::Min(3),    // about the selected pack
+            Constraint::Length(5), // letter rules and drawing
+            Constraint::Length(4), // controls
+        ])
+        .split(area);
+
+    let all = if hub.hangman_phrases { ALL_PHRASES } else { ALL_PACKS };
+    let pack_names: Vec<String> = std::iter::once(all.to_string())
+        .chain(hub.word_packs.iter().map(|pack| pack.name.clone()))
+        .collect();
+    let pack_title = if hub.hangman_phrases {
+        "📦 Zinnen (Z voor losse woorden) 📦"
+    } else {
+        "📦 Woordpakket (Z voor zinnen) 📦"
+    };
+    frame.render_widget(
+        picker_tabs(pack_names, pack_title, Color::Rgb(255, 192, 203))
+            .select(hub.selected_pack_index),
+        chunks[0],
+    );
+
+    let difficulty_names: Vec<String> = std::iter::once("alles")
+        .chain(Difficulty::ALL.iter().map(|d| d.name()))
+        .map(|name| name.to_string())
+        .collect();
+    frame.render_widget(
+        picker_tabs(difficulty_names, "🌶️ Moeilijkheid 🌶️", Color::Rgb(255, 179, 186))
+            .select(hub.selected_difficulty_index),
+        chunks[1],
+    );
+
+    frame.render_widget(
+        Paragraph::new(get_pack_details(hub))
+            .block(titled_block("📝 Over dit pakket 📝", Color::Rgb(186, 225, 255)))
+            .wrap(Wrap { trim: true }),
+        chunks[2],
+    );
+
+    frame.render_widget(
+        Paragraph::new(get_letter_rule_lines(hub))
+            .block(titled_block("🔤 Letters en tekening 🔤", Color::Rgb(255, 223, 186))),
+        chunks[3],
+    );
+
+    frame.render_widget(
+        Paragraph::new(
+            Line::from(
+                "←/→ pakket • ↑/↓ moeilijkheid • Z zinnen • E gemeen • B tijd • Enter spelen • D dagwoord • T met z'n tweeën • C Pipi raadt • Backspace terug",
+            )
+            .style(Style::default().fg(Color::Gray)),
+        )
+        .block(titled_block("🎯 Controls 🎯", Color::Rgb(186, 255, 201)))
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true }),
+        chunks[4],
+    );
+}
+
+fn titled_block(title: &str, color: Color) -> Block<'_> {
+    Block::default()
+        .borders(Borders::ALL)
+        .border_set(symbols::border::ROUNDED)
+        .title(Line::from(title).style(Style::default().fg(color).bold()))
+}
+
+fn picker_tabs<'a>(names: Vec<String>, title: &'a str, color: Color) -> Tabs<'a> {
+    Tabs::new(names)
+        .block(titled_block(title, color))
+        .style(Style::default().fg(Color::White))
+        .highlight_style(
+            Style::default()
+                .fg(Color::Rgb(255, 105, 180))
+                .bg(Color::Rgb(50, 50, 50))
+                .bold(),
+        )
+}
+
+fn get_letter_rule_lines(hub: &GameHub) -> Vec<Line<'static>> {
+    let rules = hub.profile().settings.hangman_letters;
+    let on_off = |on: bool| {
+        if on {
+            Span::styled("[aan] ", Style::default().fg(Color::Green).bold())
+        } else {
+            Span::styled("[uit] ", Style::default().fg(Color::DarkGray).bold())
+        }
+    };
+
+    vec![
+        Line::from(vec![
+            on_off(rules.fold_accents),
+            Span::raw("A: e raadt ook é, ë, è en ê"),
+        ]),
+        Line::from(vec![
+            on_off(rules.ij_as_one_letter),
+            Span::raw("I: ij is één letter, die raad je met de y"),
+        ]),
+        Line::from(vec![
+            Span::styled(
+                format!("[{}] ", hub.profile().settings.gallows),
+                Style::default().fg(Color::Rgb(255, 105, 180)).bold(),
+            ),
+            Span::raw("G: wat er getekend wordt als je mis raadt"),
+        ]),
+    ]
+}
+
+fn get_daily_line(hub: &GameHub) -> Line<'static> {
+    let date = today();
+    let profile = hub.profile();
+    let daily = &profile.daily;
+    let pack = hub.word_selection().name();
+
+    let status = if daily.finished_on(&pack, &day_key(date)) {
+        // the emoji row of today's summary
+        let marks = daily.results[&pack].summary.lines().nth(1).unwrap_or_default();
+        format!("vandaag: {}", marks)
+    } else if daily.played_on(&pack, &day_key(date)) {
+        "woord van de dag al geprobeerd".to_string()
+    } else {
+        "woord van de dag (D)".to_string()
+    };
+
+    Line::from(format!("📅 {} • 🔥 {}", status, daily.streak(&pack, date)))
+        .style(Style::default().fg(Color::Rgb(186, 255, 201)))
+}
+
+fn get_pack_details(hub: &GameHub) -> Vec<Line<'static>> {
+    let selection = hub.word_selection();
+    let packs: Vec<&WordPack> = hub
+        .word_packs
+        .iter()
+        .filter(|pack| selection.pack.as_ref().is_none_or(|name| *name == pack.name))
+        .collect();
+
+    let unit = if hub.hangman_phrases { "zinnen" } else { "woorden" };
+
+    let mut lines = Vec::new();
+    match packs.as_slice() {
+        [pack] => {
+            lines.push(Line::from(format!("categorie: {}", pack.category)).bold());
+            lines.push(Line::from(format!("taal: {}", pack.language)));
+        }
+        _ => lines.push(Line::from(format!("alle {} pakketten door elkaar", packs.len())).bold()),
+    }
+    if hub.hangman_time != TimeMode::Off {
+        lines.push(
+            Line::from(format!("⏱️ {}", hub.hangman_time.name()))
+                .style(Style::default().fg(Color::LightYellow).bold()),
+        );
+    }
+    lines.push(get_daily_line(hub));
+    if hub.hangman_evil {
+        lines.push(
+            Line::from("😈 gemeen: het spel kiest pas aan het eind welk woord het was")
+                .style(Style::default().fg(Color::LightRed).bold()),
+        );
+    }
+    lines.push(Line::from(""));
+
+    // how many words there are per difficulty, so you know what you're getting into
+    for difficulty in Difficulty::ALL {
+        let count = packs
+            .iter()
+            .flat_map(|pack| pack.words.iter().map(move |entry| pack.difficulty_of(entry)))
+            .filter(|d| *d == difficulty)
+            .count();
+        lines.push(Line::from(format!("{}: {} {}", difficulty.name(), count, unit)));
+    }
+
+    let matching = packs
+        .iter()
+        .flat_map(|pack| pack.words.iter().map(move |entry| (*pack, entry)))
+        .filter(|(pack, entry)| selection.allows(pack, entry))
+        .count();
+    lines.push(Line::from(""));
+    lines.push(
+        Line::from(format!("{} {} passen bij je keuze", matching, unit))
+            .style(Style::default().fg(Color::Rgb(255, 223, 186))),
+    );
+
+    lines
+}
@@ -0,0 +1,222 @@
+use crate::games::hangman::MAX_GUESSES;
+use crate::hub::GameHub;
+use crate::profile::{HangmanStats, HighScore};
+use crate::ui::dashboard::get_gradient_block;
+use crate::ui::hangman_ui::get_panic_face;
+use ratatui::prelude::*;
+use ratatui::widgets::{Bar, BarChart, BarGroup, Block, Borders, Gauge, Paragraph, Sparkline};
+
+pub fn render_hangman_stats(frame: &mut Frame, hub: &GameHub) {
+    let block = get_gradient_block("🪢 Galgje statistiekjes (G of Backspace om terug te gaan) 🪢");
+    frame.render_widget(block, frame.area());
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(2)
+        .constraints([
+            Constraint::Length(4), // totals and win rate
+            Constraint::Min(6),    // missed letters and high scores
+            Constraint::Length(7), // wrong guesses and lost words
+        ])
+        .split(frame.area());
+
+    let profile = hub.profile();
+    let stats = &profile.hangman;
+    let top = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(chunks[0]);
+    render_totals(stats, frame, top[0]);
+    render_win_rate(stats, frame, top[1]);
+
+    let middle = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+        .split(chunks[1]);
+    render_missed_letters(stats, frame, middle[0]);
+
+    // the pack that is picked in the hangman setup, ←/→ picks another one
+    let pack = hub.word_selection().name();
+    render_leaderboard(&pack, stats.leaderboard(&pack), frame, middle[1]);
+
+    let bottom = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+        .split(chunks[2]);
+    render_wrong_guesses(stats, frame, bottom[0]);
+    render_lost_words(stats, frame, bottom[1]);
+}
+
+fn titled_block(title: String, color: Color) -> Block<'static> {
+    Block::default()
+        .borders(Borders::ALL)
+        .border_set(symbols::border::ROUNDED)
+        .title(Line::from(title).style(Style::default().fg(color).bold()))
+}
+
+fn render_totals(stats: &HangmanStats, frame: &mut Frame, area: Rect) {
+    let highlight = Style::default().fg(Color::Rgb(255, 105, 180)).bold();
+    let lines = vec![
+        Line::from(vec![
+            Span::styled(stats.rounds.to_string(), highlight),
+            Span::raw(" gespeeld, "),
+            Span::styled(stats.wins.to_string(), highlight),
+            Span::raw(" gewonnen"),
+        ]),
+        Line::from(vec![
+            Span::raw("reeks "),
+            Span::styled(stats.current_streak.to_string(), highlight),
+            Span::raw(", beste "),
+            Span::styled(stats.best_streak.to_string(), highlight),
+        ]),
+    ];
+
+    frame.render_widget(
+        Paragraph::new(lines)
+            .block(titled_block("📋 Overzicht 📋".to_string(), Color::Rgb(255, 192, 203))),
+        area,
+    );
+}
+
+// the panic meter turned around, winning keeps Pipi calm
+fn render_win_rate(stats: &HangmanStats, frame: &mut Frame, area: Rect) {
+    let win_rate = stats.win_rate();
+    let (face, color) = get_panic_face(1.0 - win_rate);
+
+    let gauge_color = match win_rate {
+        _ if win_rate >= 0.50 => Color::Green,
+        _ if win_rate >= 0.25 => Color::Yellow,
+        _ => Color::Red,
+    };
+
+    frame.render_widget(
+        Gauge::default()
+            .block(titled_block(format!("Gewonnen {}", face), color))
+            .gauge_style(Style::default().fg(gauge_color).bg(Color::Black))
+            .percent((win_rate * 100.0) as u16),
+        area,
+    );
+}
+
+fn render_missed_letters(stats: &HangmanStats, frame: &mut Frame, area: Rect) {
+    let block = titled_block("🔤 Vaakst mis geraden 🔤".to_string(), Color::Rgb(255, 179, 186));
+
+    // one bar and a gap per letter
+    let letters = stats.most_missed_letters((area.width.saturating_sub(2) / 4) as usize);
+    if letters.is_empty() {
+        frame.render_widget(
+            Paragraph::new("nog geen letters mis geraden, knap hoor!")
+                .style(Style::default().fg(Color::Gray).italic())
+                .block(block),
+            area,
+        );
+        return;
+    }
+
+    let bars: Vec<Bar> = letters
+        .iter()
+        .map(|(letter, count)| {
+            Bar::default()
+                .value(*count as u64)
+                .label(Line::from(letter.to_string()))
+        })
+        .collect();
+
+    frame.render_widget(
+        BarChart::default()
+            .block(block)
+            .data(BarGroup::default().bars(&bars))
+            .bar_width(3)
+            .bar_gap(1)
+            .bar_style(Style::default().fg(Color::Rgb(255, 179, 186)))
+            .value_style(
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Rgb(255, 179, 186))
+                    .bold(),
+            )
+            .label_style(Style::default().fg(Color::Rgb(186, 225, 255))),
+        area,
+    );
+}
+
+fn render_leaderboard(pack: &str, scores: &[HighScore], frame: &mut Frame, area: Rect) {
+    let block = titled_block(format!("🏆 {} (←/→) 🏆", pack), Color::Rgb(255, 255, 186));
+
+    let lines: Vec<Line> = if scores.is_empty() {
+        let empty = Line::from("nog geen punten gescoord");
+        vec![empty.style(Style::default().fg(Color::Gray).italic())]
+    } else {
+        scores
+            .iter()
+            .enumerate()
+            .map(|(place, score)| {
+                let medal = match place {
+                    0 => "🥇".to_string(),
+                    1 => "🥈".to_string(),
+                    2 => "🥉".to_string(),
+                    _ => format!("{}.", place + 1),
+                };
+                Line::from(vec![
+                    Span::raw(format!("{} ", medal)),
+                    Span::styled(
+                        format!("{} ", score.points),
+                        Style::default().fg(Color::Rgb(255, 255, 186)).bold(),
+                    ),
+                    Span::raw(score.word.clone()),
+                    Span::styled(format!(" {}", score.day), Style::default().fg(Color::DarkGray)),
+                ])
+            })
+            .collect()
+    };
+
+    frame.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+fn render_wrong_guesses(stats: &HangmanStats, frame: &mut Frame, area: Rect) {
+    let average = stats.average_wrong_guesses();
+    let (face, color) = get_panic_face(average / MAX_GUESSES as f64);
+    let title = format!("Fouten per rondje, gemiddeld {:.1} {}", average, face);
+
+    // the newest rounds, as many as fit
+    let data: Vec<u64> = stats
+        .recent_wrong_guesses
+        .iter()
+        .rev()
+        .take(area.width.saturating_sub(2) as usize)
+        .rev()
+        .map(|wrong| *wrong as u64)
+        .collect();
+
+    frame.render_widget(
+        Sparkline::default()
+            .block(titled_block(title, color))
+            .data(&data)
+            .max(MAX_GUESSES as u64)
+            .style(Style::default().fg(color)),
+        area,
+    );
+}
+
+fn render_lost_words(stats: &HangmanStats, frame: &mut Frame, area: Rect) {
+    let words = stats.most_lost_words(area.height.saturating_sub(2) as usize);
+    let lines: Vec<Line> = if words.is_empty() {
+        vec![Line::from("nog niks verloren").style(Style::default().fg(Color::Gray).italic())]
+    } else {
+        words
+            .iter()
+            .map(|(word, count)| {
+                Line::from(vec![
+                    Span::styled(format!("{}x ", count), Style::default().fg(Color::LightRed).bold()),
+                    Span::raw(word.to_string()),
+                ])
+            })
+            .collect()
+    };
+
+    frame.render_widget(
+        Paragraph::new(lines)
+            .block(titled_block("💀 Vaakst verloren 💀".to_string(), Color::Rgb(186, 225, 255))),
+        area,
+    );
+}
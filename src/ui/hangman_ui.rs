@@ -1,62 +1,47 @@
-use crate::games::hangman::HangmanGame;
+use crate::games::gallows::GallowsArt;
+use crate::games::hangman::{HangmanGame, WRONG_SOLVE_COST};
+use crate::games::timer::TimeMode;
+use crate::ui::hot_seat_ui::{get_tally_line, render_secret_entry};
+use crate::ui::keyboard::{is_on_keyboard, render_keyboard, KEYBOARD_HEIGHT};
+use crate::ui::centered_rect;
 use colorgrad::GradientBuilder;
 use ratatui::prelude::*;
-use ratatui::widgets::{Block, Borders, Gauge, Paragraph, Wrap};
+use ratatui::widgets::{Block, Borders, Clear, Gauge, Paragraph, Wrap};
 use tui_gradient_block::gradient_block::GradientBlock;
 use tui_gradient_block::types::G;
+use unicode_width::UnicodeWidthStr;
 
 pub fn render_hangman_game(game: &HangmanGame, frame: &mut Frame, area: Rect) {
-    let block = get_gradient_block(
-        "💖 Jelmers galgje voor Susan :o <3 (Backspace to return | F5 to restart) 💖",
-    );
+    if let Some(hot_seat) = &game.hot_seat {
+        if let Some(entry) = &hot_seat.entry {
+            render_secret_entry(hot_seat, entry, frame, area);
+            return;
+        }
+    }
+
+    let block = get_gradient_block(if game.tournament {
+        "💖 Jelmers galgje voor Susan :o <3 (F2 geeft de ronde op) 💖"
+    } else {
+        "💖 Jelmers galgje voor Susan :o <3 (Backspace to return | F5 to restart) 💖"
+    });
     frame.render_widget(block, area);
 
-    let main_chunks = Layout::default()
-        .direction(Direction::Vertical)
-        .margin(1)
-        .constraints([
-            Constraint::Length(3), // used letters & panic meter top part
-            Constraint::Length(3), // word progress
-            Constraint::Min(1),    // game (hangman)
-        ])
-        .split(area);
+    let main_chunks = get_layout(game, area);
 
     // Only render game elements if not in the 'all words exhausted' state
     if !game.all_words_exhausted {
         let top_horizontal_chunks = Layout::default()
             .direction(Direction::Horizontal)
             .constraints([
-                Constraint::Percentage(50), // Used letters
-                Constraint::Percentage(25), // Remaining guesses
-                Constraint::Percentage(25), // Panic Meter
+                Constraint::Percentage(50), // Remaining guesses
+                Constraint::Percentage(50), // Panic Meter
             ])
             .split(main_chunks[0]);
-
-        // Used letters
-        frame.render_widget(
-            Paragraph::new(
-                game.used_characters
-                    .iter()
-                    .map(|c| c.to_string())
-                    .collect::<Vec<String>>()
-                    .join(" - "),
-            )
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .border_set(symbols::border::ROUNDED)
-                    .title(
-                        Line::from("✨ Gebruikte letters ✨")
-                            .style(Style::default().fg(Color::Rgb(255, 204, 229))),
-                    ),
-            )
-            .wrap(Wrap { trim: true }),
-            top_horizontal_chunks[0],
-        );
+        let (guesses_chunk, panic_chunk) = (top_horizontal_chunks[0], top_horizontal_chunks[1]);
 
         // remaining guesses
         frame.render_widget(
-            Paragraph::new((game.max_guesses - game.current_guess_index).to_string())
+            Paragraph::new(get_guesses_line(game))
                 .block(
                     Block::default()
                         .borders(Borders::ALL)
@@ -64,25 +49,100 @@ pub fn render_hangman_game(game: &HangmanGame, frame: &mut Frame, area: Rect) {
                         .title(
                             Line::from("💌 Aantal pogingen 💌")
                                 .style(Style::default().fg(Color::Cyan).bold()),
+                        )
+                        .title_bottom(
+                            Line::from(" / = hele woord ")
+                                .style(Style::default().fg(Color::DarkGray))
+                                .right_aligned(),
                         ),
                 )
                 .alignment(Alignment::Center)
                 .wrap(Wrap { trim: true }),
-            top_horizontal_chunks[1],
+            guesses_chunk,
         );
 
         // Panic Meter
-        render_panic_meter(game, frame, top_horizontal_chunks[2]);
+        render_panic_meter(game, frame, panic_chunk);
 
         // Word progress display
         render_current_word_progress(game, frame, main_chunks[1]);
 
+        // the used letters, and the ones you can click
+        render_keyboard(game, frame, keyboard_area(game, area));
+
         // Game body: hangman display with centered content
-        render_hangman_area(game, frame, main_chunks[2]);
+        render_hangman_area(game, frame, main_chunks[3]);
     }
 
+    show_solve_popup(game, frame, area);
+
     // Always try to show the popup if game is finished or words are exhausted
-    show_end_game_popup(game, frame);
+    show_end_game_popup(game, frame, area);
+}
+
+fn get_layout(game: &HangmanGame, area: Rect) -> std::rc::Rc<[Rect]> {
+    // phrases can take a few lines, inside the margin and the border
+    let word_lines = get_word_progress_lines(game, area.width.saturating_sub(4)).len() as u16;
+
+    Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Length(3),                     // remaining guesses & panic meter
+            Constraint::Length(word_lines.max(1) + 2), // word progress
+            Constraint::Length(KEYBOARD_HEIGHT),       // keyboard
+            Constraint::Min(1),                        // game (hangman)
+        ])
+        .split(area)
+}
+
+// Also used to find the key under the mouse
+pub fn keyboard_area(game: &HangmanGame, area: Rect) -> Rect {
+    get_layout(game, area)[2]
+}
+
+fn get_guesses_line(game: &HangmanGame) -> Line<'static> {
+    let mut spans = vec![Span::raw((game.max_guesses - game.current_guess_index).to_string())];
+
+    // what the word is worth if you get it now, it drops with every mistake
+    if game.is_scored() {
+        let points = if game.game_finished { game.points() } else { game.score().total() };
+        spans.push(Span::styled(
+            format!("  ⭐ {}", points),
+            Style::default().fg(Color::Rgb(255, 255, 186)).bold(),
+        ));
+    }
+
+    if let Some(timer) = &game.timer {
+        let seconds = timer.time_left.as_secs_f64().ceil() as u64;
+        let clock = match timer.mode {
+            TimeMode::Blitz => format!(
+                "  ⏱️ {}:{:02} • {} 🌸",
+                seconds / 60,
+                seconds % 60,
+                timer.words_solved
+            ),
+            _ => format!("  ⏱️ {}s", seconds),
+        };
+        let color = if timer.ratio_used() >= 0.75 {
+            Color::LightRed
+        } else {
+            Color::Rgb(255, 223, 186)
+        };
+        spans.push(Span::styled(clock, Style::default().fg(color).bold()));
+    }
+
+    Line::from(spans)
+}
+
+// Letters that were guessed but aren't on the keyboard, like é with the accent rule off
+fn get_off_keyboard_letters(game: &HangmanGame) -> String {
+    game.used_characters
+        .iter()
+        .filter(|c| !is_on_keyboard(**c))
+        .map(|c| c.to_string())
+        .collect::<Vec<String>>()
+        .join(" - ")
 }
 
 fn render_hangman_area(game: &HangmanGame, frame: &mut Frame, area: Rect) {
@@ -104,10 +164,17 @@ fn render_hangman_area(game: &HangmanGame, frame: &mut Frame, area: Rect) {
             .style(Style::default().fg(Color::Rgb(128, 0, 0)).bold().italic()),
     };
 
-    let border_block = Block::default()
+    let mut border_block = Block::default()
         .borders(Borders::ALL)
         .border_set(symbols::border::ROUNDED)
         .title(title);
+    let off_keyboard = get_off_keyboard_letters(game);
+    if !off_keyboard.is_empty() {
+        border_block = border_block.title_bottom(
+            Line::from(format!(" ✨ {} ", off_keyboard))
+                .style(Style::default().fg(Color::Rgb(255, 204, 229))),
+        );
+    }
 
     // Render the border
     frame.render_widget(border_block, area);
@@ -128,162 +195,127 @@ fn render_hangman_area(game: &HangmanGame, frame: &mut Frame, area: Rect) {
         .split(inner_area);
 
     // Render the hangman drawing in the middle chunk
-    frame.render_widget(get_hangman_paragraph(game), horizontal_chunks[1]);
+    frame.render_widget(
+        get_hangman_paragraph(
+            &game.art,
+            game.gallows_shown,
+            game.max_guesses,
+            game.gallows_flash > 0,
+        ),
+        horizontal_chunks[1],
+    );
 }
 
-fn get_hangman_paragraph(game: &HangmanGame) -> Paragraph<'static> {
-    const FRAMES: [&str; 10] = [
-        // Frame 0 - empty gallows
-        r#"
-+---+
-|   |
-|
-|
-|
-|
-========"#,
-        // Frame 1 - head
-        r#"
-+---+
-|   |
-|   o
-|
-|
-|
-========"#,
-        // Frame 2 - head + torso
-        r#"
-+---+
-|   |
-|   o
-|   |
-|
-|
-========"#,
-        // Frame 3 - head + torso + left arm
-        r#"
-+---+
-|   |
-|   o
-|  /|
-|
-|
-========"#,
-        // Frame 4 - head + torso + both arms
-        r#"
-+---+
-|   |
-|   o
-|  /|\
-|
-|
-========"#,
-        // Frame 5 - head + torso + both arms + left leg
-        r#"
-+---+
-|   |
-|   o
-|  /|\
-|  /
-|
-========"#,
-        // Frame 6 - complete body
-        r#"
-+---+
-|   |
-|   o
-|  /|\
-|  / \
-|
-========"#,
-        // Frame 7 - face details
-        r#"
-+---+
-|   |
-|  (o)
-|  /|\
-|  / \
-|
-========"#,
-        // Frame 8 - dead eyes
-        r#"
-+---+
-|   |
-|  (x)
-|  /|\
-|  / \
-|
-========"#,
-        // Frame 9 - final dead posture
-        r#"
-+---+
-|   |
-|  (x)
-| _/|\_
-|  / \
-|
-========"#,
-    ];
-
-    let bad_guesses = game.get_bad_guess_amount();
-    let frame_index = bad_guesses.min(9) as usize;
-    let drawing = FRAMES[frame_index].trim_start();
+// The drawing after this many misses, also drawn for the computer's misses in reverse hangman
+pub fn get_hangman_paragraph(
+    art: &GallowsArt,
+    misses: u32,
+    max_misses: u32,
+    flash: bool,
+) -> Paragraph<'static> {
+    let style = if flash {
+        Style::default().fg(Color::Rgb(255, 105, 180)).bold()
+    } else {
+        Style::default()
+    };
 
-    Paragraph::new(drawing).wrap(Wrap { trim: true })
-}
-
-fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
-    let popup_layout = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([
-            Constraint::Percentage((100 - percent_y) / 2),
-            Constraint::Percentage(percent_y),
-            Constraint::Percentage((100 - percent_y) / 2),
-        ])
-        .split(r);
-
-    Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([
-            Constraint::Percentage((100 - percent_x) / 2),
-            Constraint::Percentage(percent_x),
-            Constraint::Percentage((100 - percent_x) / 2),
-        ])
-        .split(popup_layout[1])[1]
+    Paragraph::new(art.frame_for(misses, max_misses)).style(style)
 }
 
 fn render_current_word_progress(game: &HangmanGame, frame: &mut Frame, area: Rect) {
-    let mut spans: Vec<Span> = Vec::new();
-    for c in game.word_to_guess.chars() {
-        if c == ' ' {
-            spans.push(Span::from("  "));
-        } else if game.used_characters.contains(&c) {
-            spans.push(Span::styled(
-                format!("{} ", c),
-                Style::default().fg(Color::Rgb(255, 105, 180)).bold(),
-            ));
-        } else {
-            spans.push(Span::styled(
-                "_ ",
-                Style::default().fg(Color::Rgb(220, 20, 60)).italic(),
-            ));
-        }
-    }
+    let title = if game.word_to_guess.contains(' ') {
+        "🌸 Zin 🌸"
+    } else {
+        "🌸 Woord 🌸"
+    };
 
     frame.render_widget(
-        Paragraph::new(Line::from(spans).alignment(Alignment::Center)).block(
+        Paragraph::new(get_word_progress_lines(game, area.width.saturating_sub(2))).block(
             Block::default()
                 .borders(Borders::ALL)
                 .border_set(symbols::border::ROUNDED)
                 .title(
-                    Line::from("🌸 Woord 🌸")
-                        .style(Style::default().fg(Color::Rgb(255, 192, 203)).bold()),
-                ),
+                    Line::from(title).style(Style::default().fg(Color::Rgb(255, 192, 203)).bold()),
+                )
+                .title(get_word_origin_line(game))
+                .title_bottom(get_hint_line(game)),
         ),
         area,
     );
 }
 
+// Lays the word out as "_ a _ _" and wraps phrases between words, never inside one
+fn get_word_progress_lines(game: &HangmanGame, width: u16) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
+    let mut line: Vec<Span> = Vec::new();
+    let mut line_width = 0;
+
+    for word in game.word_to_guess.split(' ').filter(|w| !w.is_empty()) {
+        let mut spans = Vec::new();
+        let mut word_width = 0;
+        for unit in game.rules.units(word) {
+            let (text, style) = if game.is_revealed(unit) {
+                (
+                    format!("{} ", unit),
+                    Style::default().fg(Color::Rgb(255, 105, 180)).bold(),
+                )
+            } else {
+                (
+                    "_ ".to_string(),
+                    Style::default().fg(Color::Rgb(220, 20, 60)).italic(),
+                )
+            };
+            word_width += text.width();
+            spans.push(Span::styled(text, style));
+        }
+
+        if !line.is_empty() && line_width + 2 + word_width > width as usize {
+            lines.push(Line::from(std::mem::take(&mut line)).alignment(Alignment::Center));
+            line_width = 0;
+        }
+        if !line.is_empty() {
+            line.push(Span::from("  "));
+            line_width += 2;
+        }
+        line.extend(spans);
+        line_width += word_width;
+    }
+
+    if !line.is_empty() {
+        lines.push(Line::from(line).alignment(Alignment::Center));
+    }
+    lines
+}
+
+// Which pack the word came from and how hard it is, e.g. "Dieren • makkelijk"
+fn get_word_origin_line(game: &HangmanGame) -> Line<'static> {
+    let origin: Vec<&str> = [
+        game.daily.as_ref().map(|_| "📅 woord van de dag"),
+        Some(game.pack_name.as_str()).filter(|name| !name.is_empty()),
+        game.difficulty.as_ref().map(|d| d.name()),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+
+    if origin.is_empty() {
+        return Line::default();
+    }
+
+    Line::from(format!(" {} ", origin.join(" • ")))
+        .style(Style::default().fg(Color::Gray).italic())
+        .right_aligned()
+}
+
+fn get_hint_line(game: &HangmanGame) -> Line<'static> {
+    match &game.hint {
+        Some(hint) if game.hint_shown => Line::from(format!(" 💡 {} ", hint))
+            .style(Style::default().fg(Color::Rgb(255, 223, 186)).italic())
+            .centered(),
+        _ => Line::default(),
+    }
+}
+
 fn pastel(col: (u8, u8, u8)) -> G {
     Box::new(
         GradientBuilder::new()
@@ -323,22 +355,29 @@ fn get_gradient_block(title_text: &str) -> GradientBlock<'_> {
         )
 }
 
-fn render_panic_meter(game: &HangmanGame, frame: &mut Frame, area: Rect) {
-    let bad_guesses = game.get_bad_guess_amount();
-    let max_bad_guesses = game.max_guesses;
-
-    let panic_ratio = (bad_guesses as f64 / max_bad_guesses as f64)
-        .min(1.0)
-        .max(0.0);
-
-    let (face, title_color) = match bad_guesses {
+// How worried Pipi looks at this much of the way to losing, also used by the hangman stats
+pub fn get_panic_face(ratio: f64) -> (&'static str, Color) {
+    match (ratio * 10.0) as u32 {
         0 => ("😄", Color::Green),
         1..=2 => ("🙂", Color::LightGreen),
         3..=4 => ("😐", Color::LightYellow),
         5..=6 => ("😟", Color::LightRed),
         7..=8 => ("😨", Color::Red),
         _ => ("😱", Color::DarkGray),
-    };
+    }
+}
+
+fn render_panic_meter(game: &HangmanGame, frame: &mut Frame, area: Rect) {
+    let bad_guesses = game.get_bad_guess_amount();
+    let max_bad_guesses = game.max_guesses;
+
+    // whichever is closer to running out, your guesses or your time
+    let time_ratio = game.timer.map_or(0.0, |timer| timer.ratio_used());
+    let panic_ratio = (bad_guesses as f64 / max_bad_guesses as f64)
+        .max(time_ratio)
+        .clamp(0.0, 1.0);
+
+    let (face, title_color) = get_panic_face(panic_ratio);
 
     let title_line = Line::from(vec![
         Span::styled("Paniek meter! ", Style::default().fg(title_color).bold()),
@@ -351,12 +390,22 @@ fn render_panic_meter(game: &HangmanGame, frame: &mut Frame, area: Rect) {
         _ => Color::Green,
     };
 
+    // the hint costs a guess, so it lives where you see what guesses cost you
+    let hint_line = if game.hint_available() {
+        Line::from(" ? = hint (-1) ").style(Style::default().fg(Color::Rgb(255, 223, 186)))
+    } else if game.hint_shown {
+        Line::from(" 💡 hint gebruikt ").style(Style::default().fg(Color::Gray).italic())
+    } else {
+        Line::default()
+    };
+
     let gauge = Gauge::default()
         .block(
             Block::default()
                 .borders(Borders::ALL)
                 .border_set(symbols::border::ROUNDED)
-                .title(title_line),
+                .title(title_line)
+                .title_bottom(hint_line.right_aligned()),
         )
         .gauge_style(Style::default().fg(gauge_color).bg(Color::Black))
         .percent((panic_ratio * 100.0) as u16);
@@ -364,12 +413,134 @@ fn render_panic_meter(game: &HangmanGame, frame: &mut Frame, area: Rect) {
     frame.render_widget(gauge, area);
 }
 
-fn show_end_game_popup(game: &HangmanGame, frame: &mut Frame) {
+fn get_answer_line(game: &HangmanGame) -> Line<'static> {
+    let answer = match &game.attribution {
+        Some(attribution) => format!("De zin was: {} — {}", game.word_to_guess, attribution),
+        None if game.word_to_guess.contains(' ') => format!("De zin was: {}", game.word_to_guess),
+        None => format!("Het woord was: {}", game.word_to_guess),
+    };
+    Line::from(answer)
+}
+
+fn get_solve_line(game: &HangmanGame) -> Line<'static> {
+    if !game.solved {
+        return Line::from("");
+    }
+
+    Line::from(format!("in één keer geraden! +{} bonus", game.solve_bonus))
+        .style(Style::default().fg(Color::Rgb(255, 223, 186)).bold())
+}
+
+fn get_points_lines(game: &HangmanGame) -> Vec<Line<'static>> {
+    if !game.has_won || !game.is_scored() {
+        return Vec::new();
+    }
+
+    let score = game.score();
+    let mut points = vec![Span::styled(
+        format!("⭐ {} punten", game.points()),
+        Style::default().fg(Color::Rgb(255, 255, 186)).bold(),
+    )];
+    if game.is_new_record() {
+        points.push(Span::styled(
+            " • 🏆 nieuw record!",
+            Style::default().fg(Color::Rgb(255, 105, 180)).bold(),
+        ));
+    }
+
+    let mut sum = format!("{} voor het woord", score.base);
+    if score.hint_penalty > 0 {
+        sum += &format!(" − {} hint", score.hint_penalty);
+    }
+    sum += &format!(" × {:.1} levens × {:.1} tempo", score.lives, score.speed);
+
+    vec![
+        Line::from(points),
+        Line::from(sum).style(Style::default().fg(Color::Gray).italic()),
+    ]
+}
+
+// The running tally with two players, or how fast you were against the clock
+fn get_mode_line(game: &HangmanGame) -> Line<'static> {
+    if let Some(hot_seat) = &game.hot_seat {
+        return get_tally_line(hot_seat);
+    }
+
+    match &game.timer {
+        Some(timer) => {
+            Line::from(format!("⏱️ {:.1} woorden per minuut", timer.words_per_minute()))
+                .style(Style::default().fg(Color::Rgb(255, 223, 186)))
+        }
+        None => Line::from(""),
+    }
+}
+
+fn get_definition_line(game: &HangmanGame) -> Line<'static> {
+    match &game.definition {
+        Some(definition) => Line::from(format!("📖 {}", definition))
+            .style(Style::default().fg(Color::Rgb(186, 225, 255)).italic()),
+        None => Line::default(),
+    }
+}
+
+fn show_solve_popup(game: &HangmanGame, frame: &mut Frame, area: Rect) {
+    let Some(input) = &game.solve_input else {
+        return;
+    };
+
+    let popuparea = centered_rect(60, 40, area);
+
+    let message_lines = vec![
+        Line::from(vec![
+            Span::styled("> ", Style::default().fg(Color::DarkGray)),
+            Span::styled(input.clone(), Style::default().fg(Color::White).bold()),
+            Span::styled("▏", Style::default().fg(Color::Rgb(255, 105, 180))),
+        ]),
+        Line::from(""),
+        Line::from("goed = meteen gewonnen, met een bonus voor elke letter die nog verstopt was")
+            .style(Style::default().fg(Color::Green).italic()),
+        Line::from(format!("fout = {} pogingen kwijt", WRONG_SOLVE_COST))
+            .style(Style::default().fg(Color::LightRed).italic()),
+        Line::from("Enter om te raden • Backspace op een leeg vakje om terug te gaan")
+            .style(Style::default().fg(Color::Gray).italic()),
+    ];
+
+    frame.render_widget(Clear, popuparea);
+    frame.render_widget(
+        Paragraph::new(message_lines)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_set(symbols::border::ROUNDED)
+                    .title(
+                        Line::from("✍️ raad het hele woord")
+                            .style(Style::default().fg(Color::Rgb(255, 192, 203)).bold()),
+                    )
+                    .style(Style::default().fg(Color::Magenta)),
+            )
+            .wrap(Wrap { trim: true }),
+        popuparea,
+    );
+}
+
+// In a tournament Enter goes on to the scoreboard, there's no restarting or leaving
+fn get_next_lines(game: &HangmanGame) -> Vec<Line<'static>> {
+    if game.tournament {
+        return vec![Line::from("Druk op Enter voor de tussenstand.").italic()];
+    }
+
+    vec![
+        Line::from("Druk op 'R' of Enter om opnieuw te starten.").italic(),
+        Line::from("Druk op 'Backspace' om terug te gaan naar het menu.").italic(),
+    ]
+}
+
+fn show_end_game_popup(game: &HangmanGame, frame: &mut Frame, area: Rect) {
     if !game.game_finished && !game.all_words_exhausted {
         return;
     }
 
-    let popuparea = centered_rect(60, 40, frame.area());
+    let popuparea = centered_rect(60, 50, area);
 
     let (title, message_lines) = if game.all_words_exhausted {
         (
@@ -380,45 +551,107 @@ fn show_end_game_popup(game: &HangmanGame, frame: &mut Frame) {
                 Line::from(""),
                 Line::from("Bedankt voor het spelen!").style(Style::default().italic()),
                 Line::from(""),
-                Line::from("Druk op 'R' of Enter om alle woorden opnieuw te starten.")
+                Line::from("Druk op 'W' om de woorden te resetten.")
                     .style(Style::default().fg(Color::LightYellow).bold().italic()),
-                Line::from("Druk op 'Backspace' om terug te gaan naar het menu.")
-                    .style(Style::default().fg(Color::LightBlue).bold().italic()),
+                Line::from(if game.tournament {
+                    "Druk op F2 om de ronde op te geven."
+                } else {
+                    "Druk op 'Backspace' om terug te gaan naar het menu."
+                })
+                .style(Style::default().fg(Color::LightBlue).bold().italic()),
             ],
         )
+    } else if let (Some(daily), Some(summary)) = (&game.daily, game.daily_summary()) {
+        let title = if game.has_won {
+            Line::from("📅 woord van de dag geraden!")
+                .style(Style::default().fg(Color::Rgb(255, 105, 180)).bold())
+        } else {
+            Line::from("📅 morgen beter!").style(Style::default().fg(Color::LightRed).bold())
+        };
+
+        let mut lines: Vec<Line> =
+            summary.lines().skip(1).map(|line| Line::from(line.to_string())).collect();
+        lines.extend(get_points_lines(game));
+        lines.extend([
+            Line::from(""),
+            get_answer_line(game).style(Style::default().fg(Color::Green).bold()),
+            get_definition_line(game),
+            Line::from(""),
+            Line::from("C kopiëren • S opslaan • Backspace terug")
+                .style(Style::default().fg(Color::LightYellow).italic()),
+        ]);
+        if let Some(status) = &daily.status {
+            lines.push(Line::from(status.clone()).style(Style::default().fg(Color::Gray)));
+        }
+        (title, lines)
+    } else if let Some(timer) = game.timer.filter(|timer| timer.run_over) {
+        (
+            // the words can run out before the clock does
+            Line::from(if timer.time_left.is_zero() {
+                "⏰ tijd is op!"
+            } else {
+                "🏁 alle woorden gehad!"
+            })
+            .style(Style::default().fg(Color::LightYellow).bold()),
+            [
+                vec![
+                    Line::from(format!(
+                        "{} {} geraden in drie minuten 🏃",
+                        timer.words_solved,
+                        if timer.words_solved == 1 { "woord" } else { "woorden" }
+                    )),
+                    get_mode_line(game),
+                    Line::from(""),
+                ],
+                get_next_lines(game),
+                vec![
+                    Line::from(""),
+                    get_answer_line(game).style(Style::default().fg(Color::DarkGray).bold()),
+                ],
+            ]
+            .concat(),
+        )
     } else if game.has_won {
         (
             Line::from("🎉 joepie de poepie!")
                 .style(Style::default().fg(Color::Rgb(255, 105, 180)).bold()),
-            vec![
-                Line::from("mulder de eindbaas heeft het weer voor elkaar! 🥳".to_string()),
-                Line::from(""),
-                Line::from("Druk op 'R' of Enter om opnieuw te starten.".to_string())
-                    .style(Style::default().italic()),
-                Line::from("Druk op 'Backspace' om terug te gaan naar het menu.".to_string())
-                    .style(Style::default().italic()),
-                Line::from(""),
-                Line::from(format!("Het woord was: {}", game.word_to_guess.clone()))
-                    .style(Style::default().fg(Color::Green).bold()),
-            ],
+            [
+                vec![
+                    Line::from("mulder de eindbaas heeft het weer voor elkaar! 🥳".to_string()),
+                    get_solve_line(game),
+                ],
+                get_points_lines(game),
+                get_next_lines(game),
+                vec![
+                    Line::from(""),
+                    get_answer_line(game).style(Style::default().fg(Color::Green).bold()),
+                    get_definition_line(game),
+                    get_mode_line(game),
+                ],
+            ]
+            .concat(),
         )
     } else {
         (
             Line::from("💀 loserrrr").style(Style::default().fg(Color::LightRed).bold()),
-            vec![
-                Line::from(" tsjongejonge, wie had dat nou weer verwacht 😢".to_string()),
-                Line::from(""),
-                Line::from("Druk op 'R' of Enter om opnieuw te starten.".to_string())
-                    .style(Style::default().italic()),
-                Line::from("Druk op 'Backspace' om terug te gaan naar het menu.".to_string())
-                    .style(Style::default().italic()),
-                Line::from(""),
-                Line::from(format!("Het woord was: {}", game.word_to_guess.clone()))
-                    .style(Style::default().fg(Color::DarkGray).bold()),
-            ],
+            [
+                vec![
+                    Line::from(" tsjongejonge, wie had dat nou weer verwacht 😢".to_string()),
+                    Line::from(""),
+                ],
+                get_next_lines(game),
+                vec![
+                    Line::from(""),
+                    get_answer_line(game).style(Style::default().fg(Color::DarkGray).bold()),
+                    get_definition_line(game),
+                    get_mode_line(game),
+                ],
+            ]
+            .concat(),
         )
     };
 
+    frame.render_widget(Clear, popuparea);
     frame.render_widget(
         Paragraph::new(message_lines)
             .block(
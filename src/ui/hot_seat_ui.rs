@@ -0,0 +1,112 @@
+use crate::games::hot_seat::{HotSeat, SecretEntry};
+use crate::ui::dashboard::get_gradient_block;
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
+
+pub fn render_secret_entry(hot_seat: &HotSeat, entry: &SecretEntry, frame: &mut Frame, area: Rect) {
+    let title = format!(
+        "🤫 Speler {} bedenkt een woord, speler {} kijkt even weg! 🤫",
+        hot_seat.setter() + 1,
+        hot_seat.guesser() + 1
+    );
+    frame.render_widget(get_gradient_block(&title), area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(2)
+        .constraints([
+            Constraint::Length(3), // secret word
+            Constraint::Length(3), // hint
+            Constraint::Length(2), // what's wrong with the word
+            Constraint::Length(3), // score
+            Constraint::Min(0),
+            Constraint::Length(3), // controls
+        ])
+        .split(area);
+
+    // the word stays hidden, you only see how long it is
+    let masked = "•".repeat(entry.word.chars().count());
+    frame.render_widget(
+        input_field(masked, "🔒 Geheim woord 🔒", !entry.editing_hint),
+        chunks[0],
+    );
+    frame.render_widget(
+        input_field(entry.hint.clone(), "💡 Hint, mag ook leeg 💡", entry.editing_hint),
+        chunks[1],
+    );
+
+    if let Some(error) = entry.error {
+        frame.render_widget(
+            Paragraph::new(Line::from(format!("✋ {}", error)))
+                .style(Style::default().fg(Color::LightRed).bold())
+                .alignment(Alignment::Center),
+            chunks[2],
+        );
+    }
+
+    frame.render_widget(
+        Paragraph::new(get_tally_line(hot_seat))
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_set(symbols::border::ROUNDED)
+                    .title(
+                        Line::from("🏆 Stand 🏆")
+                            .style(Style::default().fg(Color::Rgb(255, 223, 186)).bold()),
+                    ),
+            )
+            .alignment(Alignment::Center),
+        chunks[3],
+    );
+
+    frame.render_widget(
+        Paragraph::new(
+            Line::from("Tab woord/hint • Enter klaar • Backspace wissen")
+                .style(Style::default().fg(Color::Gray)),
+        )
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_set(symbols::border::ROUNDED)
+                .title(
+                    Line::from("🎯 Controls 🎯")
+                        .style(Style::default().fg(Color::Rgb(186, 255, 201)).bold()),
+                ),
+        )
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true }),
+        chunks[5],
+    );
+}
+
+fn input_field(text: String, title: &str, active: bool) -> Paragraph<'_> {
+    let border_color = if active {
+        Color::Rgb(255, 105, 180)
+    } else {
+        Color::DarkGray
+    };
+    let cursor = if active { "▏" } else { "" };
+
+    Paragraph::new(Line::from(vec![
+        Span::styled(text, Style::default().fg(Color::White).bold()),
+        Span::styled(cursor, Style::default().fg(Color::Rgb(255, 105, 180))),
+    ]))
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_set(symbols::border::ROUNDED)
+            .border_style(Style::default().fg(border_color))
+            .title(Line::from(title).style(Style::default().fg(Color::Rgb(255, 192, 203)).bold())),
+    )
+}
+
+pub fn get_tally_line(hot_seat: &HotSeat) -> Line<'static> {
+    Line::from(vec![
+        Span::raw("speler 1  "),
+        Span::styled(
+            format!("{} - {}", hot_seat.scores[0], hot_seat.scores[1]),
+            Style::default().fg(Color::Rgb(255, 105, 180)).bold(),
+        ),
+        Span::raw("  speler 2"),
+    ])
+}
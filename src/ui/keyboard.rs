@@ -0,0 +1,63 @@
+use crate::games::hangman::{HangmanGame, KeyState};
+use ratatui::layout::Position;
+use ratatui::prelude::*;
+use ratatui::widgets::Paragraph;
+
+const ROWS: [&str; 3] = ["qwertyuiop", "asdfghjkl", "zxcvbnm"];
+pub const KEYBOARD_HEIGHT: u16 = ROWS.len() as u16;
+
+pub fn is_on_keyboard(c: char) -> bool {
+    ROWS.iter().any(|row| row.contains(c))
+}
+
+// Where every key goes, scaled to the width there is. Drawing and clicking
+// both use this, so they always agree on where a key is.
+pub fn key_rects(area: Rect) -> Vec<(char, Rect)> {
+    // a key and the gap after it, ten of them on the top row
+    let cell = (area.width / 10).clamp(3, 8);
+    let left = area.x + area.width.saturating_sub(cell * 10 - 1) / 2;
+
+    ROWS.iter()
+        .take(area.height as usize)
+        .enumerate()
+        .flat_map(|(row, letters)| {
+            // each row sits half a key further to the right, like on a real keyboard
+            let indent = cell * row as u16 / 2;
+            letters.chars().enumerate().map(move |(column, letter)| {
+                let x = left + indent + column as u16 * cell;
+                (letter, Rect::new(x, area.y + row as u16, cell - 1, 1))
+            })
+        })
+        .filter(|(_, rect)| rect.right() <= area.right())
+        .collect()
+}
+
+pub fn key_at(area: Rect, column: u16, row: u16) -> Option<char> {
+    key_rects(area)
+        .into_iter()
+        .find(|(_, rect)| rect.contains(Position::new(column, row)))
+        .map(|(letter, _)| letter)
+}
+
+pub fn render_keyboard(game: &HangmanGame, frame: &mut Frame, area: Rect) {
+    for (letter, rect) in key_rects(area) {
+        let style = match game.key_state(letter) {
+            KeyState::Unused => Style::default().fg(Color::White).bg(Color::Rgb(60, 60, 60)),
+            KeyState::Correct => Style::default()
+                .fg(Color::Black)
+                .bg(Color::Rgb(186, 255, 201))
+                .bold(),
+            KeyState::Wrong => Style::default()
+                .fg(Color::Rgb(90, 90, 90))
+                .bg(Color::Rgb(80, 30, 40))
+                .crossed_out(),
+        };
+
+        frame.render_widget(
+            Paragraph::new(letter.to_string())
+                .style(style)
+                .alignment(Alignment::Center),
+            rect,
+        );
+    }
+}
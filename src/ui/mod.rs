@@ -1,12 +1,39 @@
+pub mod adventure_ui;
 pub mod dashboard;
+pub mod hangman_setup_ui;
+pub mod hangman_stats_ui;
 pub mod hangman_ui;
+pub mod hot_seat_ui;
+pub mod keyboard;
+pub mod reverse_hangman_ui;
 pub mod snake_ui;
-pub mod adventure_ui;
+pub mod split_ui;
+pub mod stats;
+pub mod tournament_ui;
 
 use crate::hub::{GameHub, Screen};
-use ratatui::Frame;
+use crate::utils::time::format_duration;
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+
+// Below this width screens switch to their compact layout
+pub const COMPACT_WIDTH: u16 = 70;
 
 pub fn render_ui(frame: &mut Frame, hub: &GameHub) {
+    let (min_width, min_height) = hub.min_size();
+    let area = frame.area();
+    if area.width < min_width || area.height < min_height {
+        render_too_small(frame, min_width, min_height);
+    } else {
+        render_screen(frame, hub);
+    }
+
+    if let Some((notice, _)) = &hub.notice {
+        render_notice(frame, notice);
+    }
+}
+
+fn render_screen(frame: &mut Frame, hub: &GameHub) {
     match hub.current_screen {
         Screen::Dashboard => dashboard::render_dashboard(frame, hub),
         Screen::Game => {
@@ -14,5 +41,119 @@ pub fn render_ui(frame: &mut Frame, hub: &GameHub) {
                 game.render(frame, frame.area());
             }
         }
+        Screen::Stats => stats::render_stats(frame, hub),
+        Screen::Tournament => tournament_ui::render_tournament(frame, hub),
+        Screen::Split => split_ui::render_split(frame, hub),
+        Screen::HangmanSetup => hangman_setup_ui::render_hangman_setup(frame, hub),
+        Screen::HangmanStats => hangman_stats_ui::render_hangman_stats(frame, hub),
+    }
+
+    if hub.show_break_reminder {
+        render_break_reminder(frame, hub);
     }
 }
+
+// One line over the bottom border, it goes away by itself
+fn render_notice(frame: &mut Frame, notice: &str) {
+    let area = frame.area();
+    let line_area = Rect {
+        y: area.bottom().saturating_sub(1),
+        height: area.height.min(1),
+        ..area
+    };
+
+    frame.render_widget(
+        Paragraph::new(format!(" {} ", notice))
+            .style(Style::default().fg(Color::Black).bg(Color::Rgb(255, 223, 186)))
+            .alignment(Alignment::Center),
+        line_area,
+    );
+}
+
+pub fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+    let popup_layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage((100 - percent_y) / 2),
+            Constraint::Percentage(percent_y),
+            Constraint::Percentage((100 - percent_y) / 2),
+        ])
+        .split(r);
+
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(popup_layout[1])[1]
+}
+
+fn render_too_small(frame: &mut Frame, min_width: u16, min_height: u16) {
+    let area = frame.area();
+    let lines = vec![
+        Line::from("📏 oeps, te klein 📏")
+            .style(Style::default().fg(Color::Rgb(255, 105, 180)).bold()),
+        Line::from(""),
+        Line::from("maak je terminal een beetje groter"),
+        Line::from(format!(
+            "nu {}×{}, nodig {}×{}",
+            area.width, area.height, min_width, min_height
+        ))
+        .style(Style::default().fg(Color::Rgb(255, 223, 186))),
+        Line::from(""),
+        Line::from("het spelletje wacht zolang op je 🥺")
+            .style(Style::default().fg(Color::Gray).italic()),
+    ];
+
+    // vertically centered, as far as the terminal allows
+    let height = (lines.len() as u16).min(area.height);
+    let text_area = Rect {
+        y: area.y + (area.height - height) / 2,
+        height,
+        ..area
+    };
+
+    frame.render_widget(
+        Paragraph::new(lines)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true }),
+        text_area,
+    );
+}
+
+fn render_break_reminder(frame: &mut Frame, hub: &GameHub) {
+    let popup_area = centered_rect(50, 40, frame.area());
+
+    let lines = vec![
+        Line::from(format!(
+            "je bent nu al {} lekker aan het spelen 🥰",
+            format_duration(hub.play_time.continuous)
+        )),
+        Line::from(""),
+        Line::from("tijd voor een klein pauzetje?"),
+        Line::from("even strekken, slokje water, knuffel halen 💧🧸"),
+        Line::from(""),
+        Line::from("druk op een toets om verder te spelen")
+            .style(Style::default().fg(Color::LightBlue).italic()),
+    ];
+
+    frame.render_widget(Clear, popup_area);
+    frame.render_widget(
+        Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_set(symbols::border::ROUNDED)
+                    .title(
+                        Line::from("☕ pauzetje? ☕")
+                            .style(Style::default().fg(Color::Rgb(255, 192, 203)).bold()),
+                    )
+                    .style(Style::default().fg(Color::Rgb(186, 225, 255))),
+            )
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true }),
+        popup_area,
+    );
+}
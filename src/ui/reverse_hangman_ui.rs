@@ -0,0 +1,225 @@
+use crate::games::reverse_hangman::{Phase, ReverseHangmanGame};
+use crate::ui::centered_rect;
+use crate::ui::dashboard::get_gradient_block;
+use crate::ui::hangman_ui::get_hangman_paragraph;
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+
+pub fn render_reverse_hangman(game: &ReverseHangmanGame, frame: &mut Frame, area: Rect) {
+    let block = get_gradient_block("🤖 Pipi raadt jouw woord (F5 to restart) 🤖");
+    frame.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Length(3), // the computer's guess & misses
+            Constraint::Length(3), // your word
+            Constraint::Min(1),    // gallows
+            Constraint::Length(3), // controls
+        ])
+        .split(area);
+
+    if game.phase == Phase::PickLength {
+        render_length_picker(game, frame, chunks[1]);
+        render_controls(game, frame, chunks[3]);
+        return;
+    }
+
+    let top_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+        .split(chunks[0]);
+
+    frame.render_widget(
+        Paragraph::new(get_guess_line(game))
+            .block(titled_block("🤔 Pipi vraagt 🤔", Color::Rgb(255, 192, 203)))
+            .alignment(Alignment::Center),
+        top_chunks[0],
+    );
+    frame.render_widget(
+        Paragraph::new(format!("{} / {}", game.misses, game.max_misses))
+            .block(titled_block("💀 Mis 💀", Color::Cyan))
+            .alignment(Alignment::Center),
+        top_chunks[1],
+    );
+
+    frame.render_widget(
+        Paragraph::new(get_pattern_line(game))
+            .block(
+                titled_block("🌸 Jouw woord 🌸", Color::Rgb(255, 192, 203)).title(
+                    Line::from(format!(" nog {} woorden in gedachten ", game.candidates.len()))
+                        .style(Style::default().fg(Color::Gray).italic())
+                        .right_aligned(),
+                ),
+            )
+            .alignment(Alignment::Center),
+        chunks[1],
+    );
+
+    render_gallows(game, frame, chunks[2]);
+    render_controls(game, frame, chunks[3]);
+    show_end_popup(game, frame, area);
+}
+
+fn titled_block(title: &str, color: Color) -> Block<'_> {
+    Block::default()
+        .borders(Borders::ALL)
+        .border_set(symbols::border::ROUNDED)
+        .title(Line::from(title).style(Style::default().fg(color).bold()))
+}
+
+fn render_length_picker(game: &ReverseHangmanGame, frame: &mut Frame, area: Rect) {
+    let line = Line::from(vec![
+        Span::raw("mijn woord heeft "),
+        Span::styled(
+            format!("◀ {} ▶", game.length),
+            Style::default().fg(Color::Rgb(255, 105, 180)).bold(),
+        ),
+        Span::raw(" letters"),
+        Span::styled(
+            format!("  ({} die Pipi kent)", game.words_of_length(game.length)),
+            Style::default().fg(Color::DarkGray).italic(),
+        ),
+    ]);
+
+    frame.render_widget(
+        Paragraph::new(line)
+            .block(titled_block("🤫 Denk aan een woord 🤫", Color::Rgb(255, 192, 203)))
+            .alignment(Alignment::Center),
+        area,
+    );
+}
+
+fn get_guess_line(game: &ReverseHangmanGame) -> Line<'static> {
+    match game.current_guess {
+        Some(guess) if game.phase == Phase::Marking => Line::from(vec![
+            Span::raw("zit er een "),
+            Span::styled(
+                guess.to_uppercase().to_string(),
+                Style::default().fg(Color::Rgb(255, 105, 180)).bold(),
+            ),
+            Span::raw(" in?"),
+        ]),
+        _ => Line::from("..."),
+    }
+}
+
+// Revealed letters, the letters you marked for this guess and the cursor
+fn get_pattern_line(game: &ReverseHangmanGame) -> Line<'static> {
+    let guess = game.current_guess.unwrap_or('?');
+    let mut spans = Vec::new();
+
+    for (index, slot) in game.pattern.iter().enumerate() {
+        let (text, mut style) = match slot {
+            Some(letter) => (letter.to_string(), Style::default().fg(Color::White).bold()),
+            None if game.marked[index] => (
+                guess.to_string(),
+                Style::default().fg(Color::Rgb(255, 105, 180)).bold(),
+            ),
+            None => ("_".to_string(), Style::default().fg(Color::Gray)),
+        };
+        if game.phase == Phase::Marking && index == game.cursor {
+            style = style.bg(Color::Rgb(50, 50, 50)).underlined();
+        }
+
+        spans.push(Span::styled(text, style));
+        spans.push(Span::raw(" "));
+    }
+
+    Line::from(spans)
+}
+
+fn render_gallows(game: &ReverseHangmanGame, frame: &mut Frame, area: Rect) {
+    let mut guessed: Vec<String> = game.guessed.iter().map(|c| c.to_string()).collect();
+    guessed.sort();
+
+    frame.render_widget(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_set(symbols::border::ROUNDED)
+            .title(
+                Line::from("pipi hangt er zelf aan deze keer")
+                    .style(Style::default().fg(Color::Rgb(255, 192, 203)).italic()),
+            )
+            .title_bottom(
+                Line::from(format!(" ✨ {} ", guessed.join(" - ")))
+                    .style(Style::default().fg(Color::Rgb(255, 204, 229))),
+            ),
+        area,
+    );
+
+    let inner_area = area.inner(Margin {
+        horizontal: 1,
+        vertical: 1,
+    });
+    let horizontal_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(inner_area);
+
+    frame.render_widget(
+        get_hangman_paragraph(&game.art, game.misses, game.max_misses, false),
+        horizontal_chunks[1],
+    );
+}
+
+fn render_controls(game: &ReverseHangmanGame, frame: &mut Frame, area: Rect) {
+    let controls = match game.phase {
+        Phase::PickLength => "↑/↓ aantal letters • Enter beginnen",
+        Phase::Marking => "←/→ plek • spatie markeren • Enter klaar • N zit er niet in",
+        Phase::Finished => "R of Enter opnieuw",
+    };
+
+    frame.render_widget(
+        Paragraph::new(Line::from(controls).style(Style::default().fg(Color::Gray)))
+            .block(titled_block("🎯 Controls 🎯", Color::Rgb(186, 255, 201)))
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true }),
+        area,
+    );
+}
+
+fn show_end_popup(game: &ReverseHangmanGame, frame: &mut Frame, area: Rect) {
+    if game.phase != Phase::Finished {
+        return;
+    }
+
+    let popuparea = centered_rect(60, 40, area);
+
+    let (title, message) = if game.computer_won() {
+        let word: String = game.pattern.iter().flatten().collect();
+        (
+            Line::from("🤖 hebbes!").style(Style::default().fg(Color::Rgb(255, 105, 180)).bold()),
+            format!("Pipi heeft je woord geraden: {}", word),
+        )
+    } else if game.misses >= game.max_misses {
+        (
+            Line::from("🎉 Pipi hangt!").style(Style::default().fg(Color::LightGreen).bold()),
+            "jouw woord was te moeilijk, knap hoor 🥳".to_string(),
+        )
+    } else {
+        (
+            Line::from("🤷 Pipi geeft het op").style(Style::default().fg(Color::LightGreen).bold()),
+            "alle letters geprobeerd, heb je wel eerlijk gespeeld? 🤨".to_string(),
+        )
+    };
+
+    frame.render_widget(Clear, popuparea);
+    frame.render_widget(
+        Paragraph::new(vec![
+            Line::from(message),
+            Line::from(""),
+            Line::from("Druk op 'R' of Enter om opnieuw te starten.").style(Style::default().italic()),
+        ])
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_set(symbols::border::ROUNDED)
+                .title(title)
+                .style(Style::default().fg(Color::Magenta).bold()),
+        )
+        .wrap(Wrap { trim: true }),
+        popuparea,
+    );
+}
@@ -1,34 +1,62 @@
 use ratatui::text::{Line, Text};
 use ratatui::{
     Frame,
-    layout::Rect,
-    style::{Color, Style},
+    layout::{Alignment, Rect},
+    style::{Color, Style, Stylize},
+    symbols,
     text::Span,
     widgets::{Block, Borders, Paragraph},
 };
 
-use crate::games::snake::{Direction, Position, SnakeGame};
+use crate::games::snake::{Position, SnakeGame};
 
 pub fn render_snake_game(frame: &mut Frame, area: Rect, game: &SnakeGame) {
-    let block = Block::default().title(" Snake ").borders(Borders::ALL);
+    let block = Block::default()
+        .title(format!(" Snake • lengte {} ", game.snake.len()))
+        .borders(Borders::ALL);
     frame.render_widget(block, area);
 
+    // The playing field, with a border so you can see where the walls are
+    let field = Rect {
+        x: area.x + 1,
+        y: area.y + 1,
+        width: (game.field_width + 2).min(area.width.saturating_sub(2)),
+        height: (game.field_height + 2).min(area.height.saturating_sub(2)),
+    };
+    frame.render_widget(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_set(symbols::border::ROUNDED)
+            .border_style(Style::default().fg(Color::Rgb(186, 255, 201))),
+        field,
+    );
+
+    let inner = Rect {
+        x: field.x + 1,
+        y: field.y + 1,
+        width: field.width.saturating_sub(2),
+        height: field.height.saturating_sub(2),
+    };
+    if inner.width == 0 || inner.height == 0 {
+        return;
+    }
+
     let snake = &game.snake;
     let food = &game.food;
 
     // Convert positions to string-based drawing
-    let mut buffer = vec![vec![' '; area.width as usize]; area.height as usize];
+    let mut buffer = vec![vec![' '; inner.width as usize]; inner.height as usize];
 
     for Position { x, y } in snake {
-        if let Some(row) = buffer.get_mut((*y % area.height) as usize) {
-            if let Some(cell) = row.get_mut((*x % area.width) as usize) {
+        if let Some(row) = buffer.get_mut((*y % inner.height) as usize) {
+            if let Some(cell) = row.get_mut((*x % inner.width) as usize) {
                 *cell = '•'; // or 'O', or whatever char you prefer
             }
         }
     }
 
-    if let Some(row) = buffer.get_mut((food.y % area.height) as usize) {
-        if let Some(cell) = row.get_mut((food.x % area.width) as usize) {
+    if let Some(row) = buffer.get_mut((food.y % inner.height) as usize) {
+        if let Some(cell) = row.get_mut((food.x % inner.width) as usize) {
             *cell = 'X'; // food symbol
         }
     }
@@ -44,5 +72,22 @@ pub fn render_snake_game(frame: &mut Frame, area: Rect, game: &SnakeGame) {
         .collect::<Vec<Line>>();
 
     let paragraph = Paragraph::new(Text::from(lines)).block(Block::default());
-    frame.render_widget(paragraph, area);
+    frame.render_widget(paragraph, inner);
+
+    if game.game_over {
+        let message = Paragraph::new(vec![
+            Line::from("💀 au, dat was de muur (of je eigen staart)")
+                .style(Style::default().fg(Color::LightRed).bold()),
+            Line::from(format!("{} hapjes gegeten", game.food_eaten)),
+            Line::from("Druk op 'R' of Enter om opnieuw te starten.").italic(),
+        ])
+        .alignment(Alignment::Center);
+
+        let message_area = Rect {
+            y: inner.y + inner.height / 2 - inner.height.min(3) / 2,
+            height: inner.height.min(3),
+            ..inner
+        };
+        frame.render_widget(message, message_area);
+    }
 }
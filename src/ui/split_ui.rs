@@ -0,0 +1,83 @@
+use crate::hub::GameHub;
+use crate::split::{RaceResult, Side, SplitScreen};
+use crate::ui::centered_rect;
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+pub fn render_split(frame: &mut Frame, hub: &GameHub) {
+    let Some(split) = &hub.split else {
+        return;
+    };
+
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1), // who plays with which keys
+            Constraint::Min(1),    // the two games
+        ])
+        .split(frame.area());
+
+    let halves = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(rows[1]);
+    let headers = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(rows[0]);
+
+    for (i, side) in Side::BOTH.into_iter().enumerate() {
+        let color = match side {
+            Side::Left => Color::Rgb(255, 179, 186),
+            Side::Right => Color::Rgb(186, 225, 255),
+        };
+
+        frame.render_widget(
+            Paragraph::new(Line::from(vec![
+                Span::styled(format!("speler {} ", i + 1), Style::default().fg(color).bold()),
+                Span::styled(
+                    format!("({})", split.keymap_hint(side)),
+                    Style::default().fg(Color::Gray).italic(),
+                ),
+            ]))
+            .alignment(Alignment::Center),
+            headers[i],
+        );
+
+        split.game(side).render(frame, halves[i]);
+    }
+
+    if split.result.is_some() {
+        render_race_result(frame, split);
+    }
+}
+
+fn render_race_result(frame: &mut Frame, split: &SplitScreen) {
+    let headline = match split.result {
+        Some(RaceResult::Winner(Side::Left)) => "speler 1 wint de race! 🏁",
+        Some(RaceResult::Winner(Side::Right)) => "speler 2 wint de race! 🏁",
+        _ => "niemand wint, gelijkspel 🤝",
+    };
+
+    let area = centered_rect(60, 25, frame.area());
+    frame.render_widget(Clear, area);
+    frame.render_widget(
+        Paragraph::new(vec![
+            Line::from(headline).style(Style::default().fg(Color::Rgb(255, 105, 180)).bold()),
+            Line::from(""),
+            Line::from("Enter voor nog een race • Backspace voor het menu")
+                .style(Style::default().fg(Color::Gray).italic()),
+        ])
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_set(symbols::border::ROUNDED)
+                .title(
+                    Line::from("🏎️ race 🏎️")
+                        .style(Style::default().fg(Color::Rgb(255, 223, 186)).bold()),
+                ),
+        )
+        .alignment(Alignment::Center),
+        area,
+    );
+}
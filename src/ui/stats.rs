@@ -0,0 +1,141 @@
+use crate::hub::GameHub;
+use crate::ui::dashboard::get_gradient_block;
+use crate::utils::time::{day_key, format_duration, last_days};
+use chrono::{Datelike, Weekday};
+use ratatui::prelude::*;
+use ratatui::widgets::{Bar, BarChart, BarGroup, Block, Borders, Paragraph, Wrap};
+use std::time::Duration;
+
+pub fn render_stats(frame: &mut Frame, hub: &GameHub) {
+    let block = get_gradient_block("📊 Pipis statistiekjes (G galgje • S of Backspace om terug te gaan) 📊");
+    frame.render_widget(block, frame.area());
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(2)
+        .constraints([
+            Constraint::Min(8),    // weekly chart
+            Constraint::Length(8), // this session
+        ])
+        .split(frame.area());
+
+    render_weekly_chart(frame, hub, chunks[0]);
+    render_session_summary(frame, hub, chunks[1]);
+}
+
+fn dutch_weekday(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "ma",
+        Weekday::Tue => "di",
+        Weekday::Wed => "wo",
+        Weekday::Thu => "do",
+        Weekday::Fri => "vr",
+        Weekday::Sat => "za",
+        Weekday::Sun => "zo",
+    }
+}
+
+fn render_weekly_chart(frame: &mut Frame, hub: &GameHub, area: Rect) {
+    let days = last_days(7);
+    let today = days.last().copied();
+
+    let bars: Vec<Bar> = days
+        .iter()
+        .map(|day| {
+            let seconds = if Some(*day) == today {
+                hub.played_today().as_secs()
+            } else {
+                hub.profile().play_seconds_on(&day_key(*day))
+            };
+
+            Bar::default()
+                .value(seconds / 60)
+                .text_value(format_duration(Duration::from_secs(seconds)))
+                .label(Line::from(format!(
+                    "{} {}",
+                    dutch_weekday(day.weekday()),
+                    day.day()
+                )))
+        })
+        .collect();
+
+    // spread the bars over the available width
+    let bar_width = (area.width.saturating_sub(2) / 7).saturating_sub(2).clamp(3, 12);
+
+    let chart = BarChart::default()
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_set(symbols::border::ROUNDED)
+                .title(
+                    Line::from("🗓️ minuten gespeeld deze week 🗓️")
+                        .style(Style::default().fg(Color::Rgb(255, 192, 203)).bold()),
+                ),
+        )
+        .data(BarGroup::default().bars(&bars))
+        .bar_width(bar_width)
+        .bar_gap(2)
+        .bar_style(Style::default().fg(Color::Rgb(255, 179, 186)))
+        .value_style(
+            Style::default()
+                .fg(Color::Black)
+                .bg(Color::Rgb(255, 179, 186))
+                .bold(),
+        )
+        .label_style(Style::default().fg(Color::Rgb(186, 225, 255)));
+
+    frame.render_widget(chart, area);
+}
+
+fn render_session_summary(frame: &mut Frame, hub: &GameHub, area: Rect) {
+    let mut lines = vec![Line::from(vec![
+        Span::styled("deze sessie: ", Style::default().fg(Color::Gray)),
+        Span::styled(
+            format_duration(hub.play_time.session),
+            Style::default().fg(Color::Rgb(255, 105, 180)).bold(),
+        ),
+        Span::styled("  •  aan één stuk: ", Style::default().fg(Color::Gray)),
+        Span::styled(
+            format_duration(hub.play_time.continuous),
+            Style::default().fg(Color::Rgb(255, 105, 180)).bold(),
+        ),
+    ])];
+
+    for name in hub.get_all_game_names() {
+        let played = hub
+            .play_time
+            .per_game
+            .get(name)
+            .copied()
+            .unwrap_or_default();
+        lines.push(Line::from(format!("  {}: {}", name, format_duration(played))));
+    }
+
+    let profile = hub.profile();
+    let hangman = &profile.hangman;
+    lines.push(Line::from(format!(
+        "  galgje: {} keer het hele woord in één keer geraden ({} pogingen)",
+        hangman.solves, hangman.solve_attempts
+    )));
+
+    let reminder = match profile.settings.break_reminder_minutes {
+        0 => "pauze herinnering staat uit".to_string(),
+        minutes => format!("pauze herinnering na {} minuten", minutes),
+    };
+    lines.push(Line::from(reminder).style(Style::default().fg(Color::DarkGray).italic()));
+
+    frame.render_widget(
+        Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_set(symbols::border::ROUNDED)
+                    .title(
+                        Line::from("⏱️ speeltijd ⏱️")
+                            .style(Style::default().fg(Color::Rgb(186, 225, 255)).bold()),
+                    ),
+            )
+            .wrap(Wrap { trim: true }),
+        area,
+    );
+}
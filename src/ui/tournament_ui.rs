@@ -0,0 +1,260 @@
+use crate::hub::GameHub;
+use crate::tournament::{Tournament, TournamentPhase};
+use crate::ui::dashboard::get_gradient_block;
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Paragraph, Row, Table, Tabs, Wrap};
+
+pub fn render_tournament(frame: &mut Frame, hub: &GameHub) {
+    let block = get_gradient_block("🏆 Pipis toernooitje 🏆");
+    frame.render_widget(block, frame.area());
+
+    let area = frame.area().inner(Margin {
+        horizontal: 2,
+        vertical: 2,
+    });
+
+    match &hub.tournament {
+        None => render_playlist_picker(frame, hub, area),
+        Some(tournament) => {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([
+                    Constraint::Length(7), // what's next / winner
+                    Constraint::Min(5),    // scoreboard
+                    Constraint::Length(3), // controls
+                ])
+                .split(area);
+
+            if tournament.phase == TournamentPhase::Finished {
+                render_winner(frame, tournament, chunks[0]);
+            } else {
+                render_next_round(frame, tournament, chunks[0]);
+            }
+            render_scoreboard(frame, tournament, chunks[1]);
+
+            let controls = if tournament.phase == TournamentPhase::Finished {
+                "Enter of Backspace om terug te gaan naar het menu"
+            } else {
+                "Enter om te spelen (F2 geeft een ronde op) • Backspace stopt het toernooi"
+            };
+            render_controls(frame, controls, chunks[2]);
+        }
+    }
+}
+
+fn titled_block(title: &str, color: Color) -> Block<'_> {
+    Block::default()
+        .borders(Borders::ALL)
+        .border_set(symbols::border::ROUNDED)
+        .title(Line::from(title).style(Style::default().fg(color).bold()))
+}
+
+fn render_playlist_picker(frame: &mut Frame, hub: &GameHub, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // playlists
+            Constraint::Min(3),    // steps of the selected playlist
+            Constraint::Length(3), // controls
+        ])
+        .split(area);
+
+    let names: Vec<String> = hub.playlists.iter().map(|p| p.name.clone()).collect();
+    frame.render_widget(
+        Tabs::new(names)
+            .block(titled_block(
+                "🎶 Kies een speellijst 🎶",
+                Color::Rgb(255, 192, 203),
+            ))
+            .highlight_style(
+                Style::default()
+                    .fg(Color::Rgb(255, 105, 180))
+                    .bg(Color::Rgb(50, 50, 50))
+                    .bold(),
+            )
+            .select(hub.selected_playlist_index),
+        chunks[0],
+    );
+
+    let mut lines = Vec::new();
+    if let Some(playlist) = hub.playlists.get(hub.selected_playlist_index) {
+        lines.push(
+            Line::from(format!("spelers: {}", playlist.players.join(" vs "))).bold(),
+        );
+        lines.push(Line::from(""));
+        for (i, step) in playlist.steps.iter().enumerate() {
+            let mut text = format!(
+                "{}. {} × {}",
+                i + 1,
+                step.rounds.max(1),
+                step.game.name()
+            );
+            if let Some(checkpoint) = &step.checkpoint {
+                text.push_str(&format!(" (tot {})", checkpoint));
+            }
+            lines.push(Line::from(text));
+        }
+    }
+
+    frame.render_widget(
+        Paragraph::new(lines)
+            .block(titled_block("📝 Programma 📝", Color::Rgb(186, 225, 255)))
+            .wrap(Wrap { trim: true }),
+        chunks[1],
+    );
+
+    render_controls(
+        frame,
+        "←/→ om te kiezen • Enter om te beginnen • Backspace om terug te gaan",
+        chunks[2],
+    );
+}
+
+fn render_next_round(frame: &mut Frame, tournament: &Tournament, area: Rect) {
+    let mut lines = Vec::new();
+
+    if let Some(last) = tournament.results.last() {
+        let verdict = if last.won { "gewonnen 🎉" } else { "verloren 💀" };
+        lines.push(Line::from(format!(
+            "{} heeft {} {} en krijgt {} punten",
+            tournament.player_name(last.player),
+            last.game.name(),
+            verdict,
+            last.score
+        )));
+        lines.push(Line::from(""));
+    }
+
+    if let Some(step) = tournament.current_step() {
+        lines.push(
+            Line::from(format!(
+                "volgende: {} (ronde {}/{}) • ronde {} van {} in totaal",
+                step.game.name(),
+                tournament.round_in_step + 1,
+                step.rounds.max(1),
+                tournament.results.len() + 1,
+                tournament.total_rounds()
+            ))
+            .style(Style::default().fg(Color::Rgb(255, 223, 186))),
+        );
+        lines.push(
+            Line::from(format!(
+                "{} is aan de beurt!",
+                tournament.player_name(tournament.current_player())
+            ))
+            .style(Style::default().fg(Color::Rgb(255, 105, 180)).bold()),
+        );
+    }
+
+    frame.render_widget(
+        Paragraph::new(lines)
+            .block(titled_block(
+                &tournament.playlist.name,
+                Color::Rgb(255, 192, 203),
+            ))
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true }),
+        area,
+    );
+}
+
+fn render_winner(frame: &mut Frame, tournament: &Tournament, area: Rect) {
+    let standings = tournament.standings();
+    let best = standings.first().map(|s| s.1).unwrap_or(0);
+    let winners: Vec<&str> = standings
+        .iter()
+        .filter(|(_, score)| *score == best)
+        .map(|(player, _)| tournament.player_name(*player))
+        .collect();
+
+    let headline = if winners.len() > 1 {
+        format!("gelijkspel tussen {}! 🤝", winners.join(" en "))
+    } else {
+        format!("{} wint het toernooi! 👑", winners.join(""))
+    };
+
+    let lines = vec![
+        Line::from(""),
+        Line::from(headline).style(Style::default().fg(Color::Rgb(255, 105, 180)).bold()),
+        Line::from(""),
+        Line::from(format!("met {} punten", best)).italic(),
+    ];
+
+    frame.render_widget(
+        Paragraph::new(lines)
+            .block(titled_block("🏆 Eindstand 🏆", Color::Rgb(255, 223, 186)))
+            .alignment(Alignment::Center),
+        area,
+    );
+}
+
+fn render_scoreboard(frame: &mut Frame, tournament: &Tournament, area: Rect) {
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
+        .split(area);
+
+    let standings = tournament
+        .standings()
+        .into_iter()
+        .enumerate()
+        .map(|(place, (player, score))| {
+            let medal = match place {
+                0 => "🥇",
+                1 => "🥈",
+                2 => "🥉",
+                _ => "  ",
+            };
+            Row::new(vec![
+                format!("{} {}", medal, tournament.player_name(player)),
+                score.to_string(),
+            ])
+        });
+
+    frame.render_widget(
+        Table::new(standings, [Constraint::Min(10), Constraint::Length(8)])
+            .header(Row::new(vec!["speler", "punten"]).style(Style::default().bold()))
+            .block(titled_block("💯 Scorebord 💯", Color::Rgb(186, 255, 201))),
+        chunks[0],
+    );
+
+    // newest rounds on top
+    let rounds = tournament.results.iter().enumerate().rev().map(|(i, r)| {
+        let style = if r.won {
+            Style::default().fg(Color::Rgb(186, 255, 201))
+        } else {
+            Style::default().fg(Color::DarkGray)
+        };
+        Row::new(vec![
+            format!("{}", i + 1),
+            tournament.player_name(r.player).to_string(),
+            r.game.name().to_string(),
+            format!("+{}", r.score),
+        ])
+        .style(style)
+    });
+
+    frame.render_widget(
+        Table::new(
+            rounds,
+            [
+                Constraint::Length(4),
+                Constraint::Min(8),
+                Constraint::Min(10),
+                Constraint::Length(6),
+            ],
+        )
+        .header(Row::new(vec!["#", "speler", "spel", "punten"]).style(Style::default().bold()))
+        .block(titled_block("📜 Rondes 📜", Color::Rgb(186, 225, 255))),
+        chunks[1],
+    );
+}
+
+fn render_controls(frame: &mut Frame, text: &str, area: Rect) {
+    frame.render_widget(
+        Paragraph::new(Line::from(text).style(Style::default().fg(Color::Gray)))
+            .block(titled_block("🎯 Controls 🎯", Color::Rgb(186, 255, 201)))
+            .alignment(Alignment::Center),
+        area,
+    );
+}
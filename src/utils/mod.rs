@@ -1 +1,5 @@
+pub mod clipboard;
 pub mod image;
+pub mod screenshot;
+pub mod time;
+pub mod recorder;
@@ -0,0 +1,113 @@
+use ratatui::crossterm::terminal;
+use serde_json::json;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+// An asciicast v2 file: a JSON header line followed by one [time, "o", data] line per
+// frame, and a [time, "r", "WxH"] line whenever the terminal changed size
+pub struct CastFile {
+    out: BufWriter<File>,
+    started: Instant,
+    size: (u16, u16),
+}
+
+impl CastFile {
+    pub fn create(path: impl AsRef<Path>, width: u16, height: u16) -> io::Result<Self> {
+        let mut out = BufWriter::new(File::create(path)?);
+
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or_default();
+
+        let header = json!({
+            "version": 2,
+            "width": width,
+            "height": height,
+            "timestamp": timestamp,
+            "title": "Susan's Game Hub",
+            "env": {
+                "TERM": std::env::var("TERM").unwrap_or_else(|_| "xterm-256color".to_string()),
+                "SHELL": std::env::var("SHELL").unwrap_or_default(),
+            },
+        });
+        writeln!(out, "{}", header)?;
+
+        Ok(Self {
+            out,
+            started: Instant::now(),
+            size: (width, height),
+        })
+    }
+
+    // Players need the new size before the first frame drawn for it
+    fn note_size(&mut self, width: u16, height: u16) -> io::Result<()> {
+        if self.size == (width, height) {
+            return Ok(());
+        }
+        self.size = (width, height);
+
+        let event = json!([
+            self.started.elapsed().as_secs_f64(),
+            "r",
+            format!("{}x{}", width, height),
+        ]);
+        writeln!(self.out, "{}", event)
+    }
+
+    fn write_output(&mut self, data: &[u8]) -> io::Result<()> {
+        let event = json!([
+            self.started.elapsed().as_secs_f64(),
+            "o",
+            String::from_utf8_lossy(data),
+        ]);
+        writeln!(self.out, "{}", event)?;
+        self.out.flush()
+    }
+}
+
+// Passes everything through to the real terminal, and when a cast file is attached also
+// collects the bytes of each frame and writes them out as one event when the frame is flushed
+pub struct CastRecorder<W: Write> {
+    inner: W,
+    cast: Option<CastFile>,
+    pending: Vec<u8>,
+}
+
+impl<W: Write> CastRecorder<W> {
+    pub fn new(inner: W, cast: Option<CastFile>) -> Self {
+        Self {
+            inner,
+            cast,
+            pending: Vec::new(),
+        }
+    }
+}
+
+impl<W: Write> Write for CastRecorder<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        if self.cast.is_some() {
+            self.pending.extend_from_slice(&buf[..written]);
+        }
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()?;
+
+        if let Some(cast) = &mut self.cast {
+            if !self.pending.is_empty() {
+                if let Ok((width, height)) = terminal::size() {
+                    cast.note_size(width, height)?;
+                }
+                cast.write_output(&self.pending)?;
+                self.pending.clear();
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,411 @@
+use ratatui::buffer::Buffer;
+use ratatui::style::{Color, Modifier};
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+use unicode_width::UnicodeWidthStr;
+
+const SCREENSHOT_DIR: &str = "screenshots";
+
+const DEFAULT_FG: (u8, u8, u8) = (229, 229, 229);
+const DEFAULT_BG: (u8, u8, u8) = (24, 24, 27);
+
+const CELL_WIDTH: f32 = 8.4;
+const CELL_HEIGHT: f32 = 17.0;
+const FONT_SIZE: f32 = 14.0;
+
+#[derive(Clone, Copy)]
+pub enum ScreenshotFormat {
+    Text,
+    Ansi,
+    Html,
+    Svg,
+}
+
+impl ScreenshotFormat {
+    pub const ALL: [ScreenshotFormat; 4] = [
+        ScreenshotFormat::Text,
+        ScreenshotFormat::Ansi,
+        ScreenshotFormat::Html,
+        ScreenshotFormat::Svg,
+    ];
+
+    pub fn extension(&self) -> &str {
+        match self {
+            ScreenshotFormat::Text => "txt",
+            ScreenshotFormat::Ansi => "ans",
+            ScreenshotFormat::Html => "html",
+            ScreenshotFormat::Svg => "svg",
+        }
+    }
+
+    pub fn render(&self, buffer: &Buffer) -> String {
+        match self {
+            ScreenshotFormat::Text => to_text(buffer),
+            ScreenshotFormat::Ansi => to_ansi(buffer),
+            ScreenshotFormat::Html => to_html(buffer),
+            ScreenshotFormat::Svg => to_svg(buffer),
+        }
+    }
+}
+
+// A run of neighbouring cells on one row that share the same colours and modifiers
+struct Run {
+    x: u16,
+    text: String,
+    fg: Color,
+    bg: Color,
+    modifier: Modifier,
+}
+
+fn row_runs(buffer: &Buffer, y: u16) -> Vec<Run> {
+    let area = buffer.area;
+    let mut runs: Vec<Run> = Vec::new();
+    let mut to_skip = 0;
+
+    for x in area.left()..area.right() {
+        // wide characters (emoji) leave blank filler cells behind them
+        if to_skip > 0 {
+            to_skip -= 1;
+            continue;
+        }
+
+        let cell = &buffer[(x, y)];
+        if cell.skip {
+            continue;
+        }
+
+        let symbol = cell.symbol();
+        to_skip = symbol.width().saturating_sub(1);
+
+        match runs.last_mut() {
+            Some(run) if run.fg == cell.fg && run.bg == cell.bg && run.modifier == cell.modifier => {
+                run.text.push_str(symbol);
+            }
+            _ => runs.push(Run {
+                x,
+                text: symbol.to_string(),
+                fg: cell.fg,
+                bg: cell.bg,
+                modifier: cell.modifier,
+            }),
+        }
+    }
+
+    runs
+}
+
+pub fn to_text(buffer: &Buffer) -> String {
+    let area = buffer.area;
+    let mut out = String::new();
+
+    for y in area.top()..area.bottom() {
+        let line: String = row_runs(buffer, y).into_iter().map(|r| r.text).collect();
+        out.push_str(line.trim_end());
+        out.push('\n');
+    }
+
+    out
+}
+
+pub fn to_ansi(buffer: &Buffer) -> String {
+    let area = buffer.area;
+    let mut out = String::new();
+
+    for y in area.top()..area.bottom() {
+        for run in row_runs(buffer, y) {
+            let mut codes = vec!["0".to_string()];
+            if let Some(code) = ansi_color(run.fg, false) {
+                codes.push(code);
+            }
+            if let Some(code) = ansi_color(run.bg, true) {
+                codes.push(code);
+            }
+            if run.modifier.contains(Modifier::BOLD) {
+                codes.push("1".to_string());
+            }
+            if run.modifier.contains(Modifier::DIM) {
+                codes.push("2".to_string());
+            }
+            if run.modifier.contains(Modifier::ITALIC) {
+                codes.push("3".to_string());
+            }
+            if run.modifier.contains(Modifier::UNDERLINED) {
+                codes.push("4".to_string());
+            }
+            if run.modifier.contains(Modifier::REVERSED) {
+                codes.push("7".to_string());
+            }
+            if run.modifier.contains(Modifier::CROSSED_OUT) {
+                codes.push("9".to_string());
+            }
+
+            let _ = write!(out, "\x1b[{}m{}", codes.join(";"), run.text);
+        }
+        out.push_str("\x1b[0m\n");
+    }
+
+    out
+}
+
+fn ansi_color(color: Color, background: bool) -> Option<String> {
+    let offset = if background { 10 } else { 0 };
+    let code = match color {
+        Color::Reset => return None,
+        Color::Black => 30,
+        Color::Red => 31,
+        Color::Green => 32,
+        Color::Yellow => 33,
+        Color::Blue => 34,
+        Color::Magenta => 35,
+        Color::Cyan => 36,
+        Color::Gray => 37,
+        Color::DarkGray => 90,
+        Color::LightRed => 91,
+        Color::LightGreen => 92,
+        Color::LightYellow => 93,
+        Color::LightBlue => 94,
+        Color::LightMagenta => 95,
+        Color::LightCyan => 96,
+        Color::White => 97,
+        Color::Rgb(r, g, b) => {
+            return Some(format!("{};2;{};{};{}", 38 + offset, r, g, b));
+        }
+        Color::Indexed(i) => return Some(format!("{};5;{}", 38 + offset, i)),
+    };
+
+    Some((code + offset).to_string())
+}
+
+fn rgb(color: Color, default: (u8, u8, u8)) -> (u8, u8, u8) {
+    match color {
+        Color::Reset => default,
+        Color::Black => (0, 0, 0),
+        Color::Red => (205, 49, 49),
+        Color::Green => (13, 188, 121),
+        Color::Yellow => (229, 229, 16),
+        Color::Blue => (36, 114, 200),
+        Color::Magenta => (188, 63, 188),
+        Color::Cyan => (17, 168, 205),
+        Color::Gray => (204, 204, 204),
+        Color::DarkGray => (118, 118, 118),
+        Color::LightRed => (241, 76, 76),
+        Color::LightGreen => (35, 209, 139),
+        Color::LightYellow => (245, 245, 67),
+        Color::LightBlue => (59, 142, 234),
+        Color::LightMagenta => (214, 112, 214),
+        Color::LightCyan => (41, 184, 219),
+        Color::White => (255, 255, 255),
+        Color::Rgb(r, g, b) => (r, g, b),
+        Color::Indexed(i) => indexed_rgb(i),
+    }
+}
+
+// The standard xterm 256 colour palette
+fn indexed_rgb(i: u8) -> (u8, u8, u8) {
+    match i {
+        0..=15 => {
+            const BASE: [Color; 16] = [
+                Color::Black,
+                Color::Red,
+                Color::Green,
+                Color::Yellow,
+                Color::Blue,
+                Color::Magenta,
+                Color::Cyan,
+                Color::Gray,
+                Color::DarkGray,
+                Color::LightRed,
+                Color::LightGreen,
+                Color::LightYellow,
+                Color::LightBlue,
+                Color::LightMagenta,
+                Color::LightCyan,
+                Color::White,
+            ];
+            rgb(BASE[i as usize], DEFAULT_FG)
+        }
+        16..=231 => {
+            let level = |v: u8| if v == 0 { 0 } else { 55 + v * 40 };
+            let i = i - 16;
+            (level(i / 36), level((i / 6) % 6), level(i % 6))
+        }
+        _ => {
+            let grey = 8 + (i - 232) * 10;
+            (grey, grey, grey)
+        }
+    }
+}
+
+fn hex(color: (u8, u8, u8)) -> String {
+    format!("#{:02x}{:02x}{:02x}", color.0, color.1, color.2)
+}
+
+// fg and bg as hex strings, with REVERSED already applied
+fn run_colors(run: &Run) -> (String, String) {
+    let fg = rgb(run.fg, DEFAULT_FG);
+    let bg = rgb(run.bg, DEFAULT_BG);
+
+    if run.modifier.contains(Modifier::REVERSED) {
+        (hex(bg), hex(fg))
+    } else {
+        (hex(fg), hex(bg))
+    }
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+pub fn to_html(buffer: &Buffer) -> String {
+    let area = buffer.area;
+    let mut body = String::new();
+
+    for y in area.top()..area.bottom() {
+        for run in row_runs(buffer, y) {
+            let (fg, bg) = run_colors(&run);
+            let mut style = format!("color:{};background:{}", fg, bg);
+            if run.modifier.contains(Modifier::BOLD) {
+                style.push_str(";font-weight:bold");
+            }
+            if run.modifier.contains(Modifier::ITALIC) {
+                style.push_str(";font-style:italic");
+            }
+            if run.modifier.contains(Modifier::UNDERLINED) {
+                style.push_str(";text-decoration:underline");
+            }
+            if run.modifier.contains(Modifier::DIM) {
+                style.push_str(";opacity:0.6");
+            }
+
+            let _ = write!(
+                body,
+                "<span style=\"{}\">{}</span>",
+                style,
+                escape_xml(&run.text)
+            );
+        }
+        body.push('\n');
+    }
+
+    format!(
+        "<!DOCTYPE html>\n\
+         <html>\n\
+         <head>\n\
+         <meta charset=\"utf-8\">\n\
+         <title>Susan's Game Hub</title>\n\
+         <style>\n\
+         body {{ background: {bg}; margin: 0; padding: 1em; }}\n\
+         pre {{ font-family: 'DejaVu Sans Mono', Menlo, Consolas, monospace; font-size: {font}px; line-height: {line}px; color: {fg}; margin: 0; }}\n\
+         </style>\n\
+         </head>\n\
+         <body>\n\
+         <pre>{body}</pre>\n\
+         </body>\n\
+         </html>\n",
+        bg = hex(DEFAULT_BG),
+        fg = hex(DEFAULT_FG),
+        font = FONT_SIZE,
+        line = CELL_HEIGHT,
+        body = body,
+    )
+}
+
+pub fn to_svg(buffer: &Buffer) -> String {
+    let area = buffer.area;
+    let width = area.width as f32 * CELL_WIDTH;
+    let height = area.height as f32 * CELL_HEIGHT;
+
+    let mut backgrounds = String::new();
+    let mut texts = String::new();
+
+    for y in area.top()..area.bottom() {
+        let top = (y - area.top()) as f32 * CELL_HEIGHT;
+
+        for run in row_runs(buffer, y) {
+            let (fg, bg) = run_colors(&run);
+            let left = (run.x - area.left()) as f32 * CELL_WIDTH;
+            let columns = run.text.width() as f32;
+
+            if bg != hex(DEFAULT_BG) {
+                let _ = writeln!(
+                    backgrounds,
+                    "<rect x=\"{:.1}\" y=\"{:.1}\" width=\"{:.1}\" height=\"{:.1}\" fill=\"{}\"/>",
+                    left,
+                    top,
+                    columns * CELL_WIDTH,
+                    CELL_HEIGHT,
+                    bg
+                );
+            }
+
+            if run.text.trim().is_empty() {
+                continue;
+            }
+
+            let mut attributes = format!("fill=\"{}\"", fg);
+            if run.modifier.contains(Modifier::BOLD) {
+                attributes.push_str(" font-weight=\"bold\"");
+            }
+            if run.modifier.contains(Modifier::ITALIC) {
+                attributes.push_str(" font-style=\"italic\"");
+            }
+            if run.modifier.contains(Modifier::UNDERLINED) {
+                attributes.push_str(" text-decoration=\"underline\"");
+            }
+            if run.modifier.contains(Modifier::DIM) {
+                attributes.push_str(" opacity=\"0.6\"");
+            }
+
+            // textLength keeps every run on the terminal grid, whatever font the viewer has
+            let _ = writeln!(
+                texts,
+                "<text x=\"{:.1}\" y=\"{:.1}\" textLength=\"{:.1}\" lengthAdjust=\"spacingAndGlyphs\" {}>{}</text>",
+                left,
+                top + CELL_HEIGHT * 0.8,
+                columns * CELL_WIDTH,
+                attributes,
+                escape_xml(&run.text)
+            );
+        }
+    }
+
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w:.0}\" height=\"{h:.0}\" viewBox=\"0 0 {w:.1} {h:.1}\">\n\
+         <rect width=\"100%\" height=\"100%\" fill=\"{bg}\"/>\n\
+         {backgrounds}\
+         <g font-family=\"'DejaVu Sans Mono', Menlo, Consolas, monospace\" font-size=\"{font}\" xml:space=\"preserve\">\n\
+         {texts}\
+         </g>\n\
+         </svg>\n",
+        w = width,
+        h = height,
+        bg = hex(DEFAULT_BG),
+        font = FONT_SIZE,
+        backgrounds = backgrounds,
+        texts = texts,
+    )
+}
+
+// Writes the buffer in every format, e.g. screenshots/suus-<millis>.svg
+pub fn save_screenshot(buffer: &Buffer) -> io::Result<()> {
+    fs::create_dir_all(SCREENSHOT_DIR)?;
+
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis())
+        .unwrap_or_default();
+
+    for format in ScreenshotFormat::ALL {
+        let path =
+            PathBuf::from(SCREENSHOT_DIR).join(format!("suus-{}.{}", stamp, format.extension()));
+        fs::write(path, format.render(buffer))?;
+    }
+
+    Ok(())
+}
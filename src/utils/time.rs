@@ -0,0 +1,28 @@
+use chrono::{Days, Local, NaiveDate};
+use std::time::Duration;
+
+pub fn today() -> NaiveDate {
+    Local::now().date_naive()
+}
+
+pub fn day_key(date: NaiveDate) -> String {
+    date.format("%Y-%m-%d").to_string()
+}
+
+// The last `days` dates, oldest first, ending with today
+pub fn last_days(days: u64) -> Vec<NaiveDate> {
+    let today = today();
+    (0..days)
+        .rev()
+        .filter_map(|offset| today.checked_sub_days(Days::new(offset)))
+        .collect()
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let minutes = duration.as_secs() / 60;
+    if minutes >= 60 {
+        format!("{}u {:02}m", minutes / 60, minutes % 60)
+    } else {
+        format!("{}m", minutes)
+    }
+}
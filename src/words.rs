@@ -0,0 +1,324 @@
+use crate::cli::WordsCommand;
+use crate::games::word_packs::{
+    parse_phrase_pack, sorted_paths, WordEntry, WordPack, PHRASE_PACKS_DIR, WORD_PACKS_DIR,
+};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+// words with fewer or more letters than this are probably a typo
+const MIN_LETTERS: usize = 3;
+const MAX_LETTERS: usize = 24;
+const BAR_WIDTH: usize = 30;
+
+// Runs one of the word list tools, false if it found something wrong
+pub fn run(command: WordsCommand) -> io::Result<bool> {
+    let mut out = io::stdout().lock();
+    let result = match command {
+        WordsCommand::Check(packs) => check(&mut out, &pack_files(packs, true)),
+        WordsCommand::Stats(packs) => stats(&mut out, &pack_files(packs, false)).map(|_| true),
+        WordsCommand::Import { source, pack } => {
+            import(&mut out, &source, &pack_path(&pack)).map(|_| true)
+        }
+    };
+
+    // `words stats | head` stops reading halfway, that's not our problem
+    match result {
+        Err(error) if error.kind() == io::ErrorKind::BrokenPipe => Ok(true),
+        result => result,
+    }
+}
+
+// The phrase packs only when asked for, their lengths would drown out the words
+fn pack_files(packs: Vec<PathBuf>, with_phrases: bool) -> Vec<PathBuf> {
+    if !packs.is_empty() {
+        return packs.iter().map(|pack| pack_path(&pack.to_string_lossy())).collect();
+    }
+
+    let mut paths: Vec<PathBuf> = sorted_paths(WORD_PACKS_DIR)
+        .into_iter()
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json" || ext == "txt"))
+        .collect();
+    if with_phrases {
+        paths.extend(
+            sorted_paths(PHRASE_PACKS_DIR)
+                .into_iter()
+                .filter(|path| path.extension().is_some_and(|ext| ext == "json")),
+        );
+    }
+    paths
+}
+
+// By the folder it's in, so ./data/hangman/zinnen/... or a full path counts too
+fn is_phrase_pack(path: &Path) -> bool {
+    path.parent().and_then(Path::file_name) == Path::new(PHRASE_PACKS_DIR).file_name()
+}
+
+// "dieren" is data/hangman/dieren.json (or .txt), anything with an extension is a path
+fn pack_path(pack: &str) -> PathBuf {
+    let path = PathBuf::from(pack);
+    if path.extension().is_some() {
+        return path;
+    }
+
+    let dir = Path::new(WORD_PACKS_DIR);
+    let json = dir.join(format!("{}.json", pack));
+    if json.exists() {
+        json
+    } else {
+        dir.join(format!("{}.txt", pack))
+    }
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map_or_else(|| path.display().to_string(), |name| name.to_string_lossy().to_string())
+}
+
+// Every word in a pack with where it is, blank ones included so check can find them
+fn read_words(path: &Path) -> io::Result<Vec<(String, String)>> {
+    let contents = fs::read_to_string(path)?;
+
+    if path.extension().is_some_and(|ext| ext == "json") {
+        let (pack, unit) = match is_phrase_pack(path) {
+            true => (parse_phrase_pack(&contents), "zin"),
+            false => (serde_json::from_str::<WordPack>(&contents), "woord"),
+        };
+        let pack = pack.map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+        return Ok(pack
+            .words
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| (format!("{} {}", unit, index + 1), entry.word().to_string()))
+            .collect());
+    }
+
+    Ok(contents
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (format!("regel {}", index + 1), line.to_string()))
+        .collect())
+}
+
+// what counts as the same word
+fn word_key(word: &str) -> String {
+    word.trim().to_lowercase()
+}
+
+fn letter_count(word: &str) -> usize {
+    word.chars().filter(|c| c.is_alphabetic()).count()
+}
+
+// Anything that isn't a letter is on the board from the start, like the dots
+// in a.u.b., so only what you can't see or can't guess is a problem
+fn word_problem(word: &str) -> Option<String> {
+    if word.trim().is_empty() {
+        return Some("lege regel".to_string());
+    }
+    if word.trim() != word {
+        return Some("spaties aan het begin of eind".to_string());
+    }
+    if let Some(c) = word.chars().find(|c| c.is_control() || (c.is_whitespace() && *c != ' ')) {
+        return Some(format!("onzichtbaar teken {:?}", c));
+    }
+    if letter_count(word) == 0 {
+        return Some("niets te raden, geen letters".to_string());
+    }
+
+    None
+}
+
+// Not wrong as such, but worth a second look
+fn length_warning(word: &str) -> Option<String> {
+    match letter_count(word) {
+        letters if letters < MIN_LETTERS => Some(format!("maar {} letters", letters)),
+        letters if letters > MAX_LETTERS => Some(format!("wel {} letters", letters)),
+        _ => None,
+    }
+}
+
+fn check(out: &mut impl Write, paths: &[PathBuf]) -> io::Result<bool> {
+    let mut problems = 0;
+    let mut warnings = 0;
+    // word -> the first pack it was in, to find words that are in two packs
+    let mut seen_in: HashMap<String, String> = HashMap::new();
+
+    for path in paths {
+        let name = file_name(path);
+        let words = match read_words(path) {
+            Ok(words) => words,
+            Err(error) => {
+                writeln!(out, "{}: kan ik niet lezen ({})", name, error)?;
+                problems += 1;
+                continue;
+            }
+        };
+
+        // the letter limits are for words, a saying is supposed to be long
+        let phrases = is_phrase_pack(path);
+        let mut seen_here: HashMap<String, &str> = HashMap::new();
+        for (place, word) in &words {
+            let problem = word_problem(word).or_else(|| {
+                let key = word_key(word);
+                if let Some(first) = seen_here.insert(key.clone(), place) {
+                    return Some(format!("dubbel, staat ook op {}", first));
+                }
+                match seen_in.get(&key) {
+                    Some(other) if *other != name => Some(format!("staat ook in {}", other)),
+                    Some(_) => None,
+                    None => {
+                        seen_in.insert(key, name.clone());
+                        None
+                    }
+                }
+            });
+
+            if let Some(problem) = problem {
+                writeln!(out, "{}, {}: {:?} {}", name, place, word, problem)?;
+                problems += 1;
+            } else if let Some(warning) = length_warning(word).filter(|_| !phrases) {
+                writeln!(out, "{}, {}: {:?} {} (let op)", name, place, word, warning)?;
+                warnings += 1;
+            }
+        }
+    }
+
+    match problems {
+        0 => writeln!(out, "✓ {} pakketten nagekeken, alles in orde", paths.len())?,
+        1 => writeln!(out, "1 probleempje gevonden")?,
+        _ => writeln!(out, "{} probleempjes gevonden", problems)?,
+    }
+    if warnings > 0 {
+        writeln!(
+            out,
+            "en {} keer een opvallende lengte, die mogen blijven als het zo hoort",
+            warnings
+        )?;
+    }
+    // only real problems count, a long word can be on purpose
+    Ok(problems == 0)
+}
+
+fn bar(count: usize, max: usize) -> String {
+    "█".repeat((count * BAR_WIDTH).div_ceil(max.max(1)))
+}
+
+fn stats(out: &mut impl Write, paths: &[PathBuf]) -> io::Result<()> {
+    let mut words = Vec::new();
+    for path in paths {
+        let pack_words: Vec<String> = read_words(path)?
+            .into_iter()
+            .map(|(_, word)| word)
+            .filter(|word| !word.trim().is_empty())
+            .collect();
+        writeln!(out, "{}: {} woorden", file_name(path), pack_words.len())?;
+        words.extend(pack_words);
+    }
+
+    let mut lengths: BTreeMap<usize, usize> = BTreeMap::new();
+    let mut letters: HashMap<char, usize> = HashMap::new();
+    for word in &words {
+        *lengths.entry(letter_count(word)).or_default() += 1;
+        for letter in word.chars().filter(|c| c.is_alphabetic()) {
+            *letters.entry(letter.to_lowercase().next().unwrap_or(letter)).or_default() += 1;
+        }
+    }
+
+    writeln!(out, "\nop lengte, in letters ({} woorden):", words.len())?;
+    let most = lengths.values().copied().max().unwrap_or(0);
+    for (length, count) in &lengths {
+        writeln!(out, "{:>4} {} {}", length, bar(*count, most), count)?;
+    }
+
+    let mut letters: Vec<(char, usize)> = letters.into_iter().collect();
+    letters.sort_by_key(|(letter, count)| (std::cmp::Reverse(*count), *letter));
+    let total: usize = letters.iter().map(|(_, count)| count).sum();
+    let most = letters.first().map_or(0, |(_, count)| *count);
+
+    writeln!(out, "\nop letter:")?;
+    for (letter, count) in &letters {
+        let percentage = *count as f64 * 100.0 / total as f64;
+        writeln!(out, "{:>4} {} {:.1}%", letter, bar(*count, most), percentage)?;
+    }
+
+    Ok(())
+}
+
+// One word per line, or the first column of a csv (a "woord" header is skipped)
+fn read_source(path: &Path) -> io::Result<Vec<String>> {
+    let contents = fs::read_to_string(path)?;
+    let csv = path.extension().is_some_and(|ext| ext == "csv");
+
+    Ok(contents
+        .lines()
+        .map(|line| match csv {
+            true => line.split([',', ';', '\t']).next().unwrap_or_default(),
+            false => line,
+        })
+        .map(|word| word.trim().trim_matches('"').split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|word| !word.is_empty())
+        .enumerate()
+        .filter(|(index, word)| {
+            let header = matches!(word_key(word).as_str(), "woord" | "word");
+            !(csv && *index == 0 && header)
+        })
+        .map(|(_, word)| word)
+        .collect())
+}
+
+fn import(out: &mut impl Write, source: &Path, pack: &Path) -> io::Result<()> {
+    let mut known: HashSet<String> = match pack.exists() {
+        true => read_words(pack)?.iter().map(|(_, word)| word_key(word)).collect(),
+        false => HashSet::new(),
+    };
+
+    let mut added = Vec::new();
+    let mut already_there = 0;
+    for word in read_source(source)? {
+        if let Some(problem) = word_problem(&word) {
+            writeln!(out, "overgeslagen: {:?} {}", word, problem)?;
+        } else if known.insert(word_key(&word)) {
+            if let Some(warning) = length_warning(&word) {
+                writeln!(out, "let op: {:?} {}", word, warning)?;
+            }
+            added.push(word);
+        } else {
+            already_there += 1;
+        }
+    }
+
+    if pack.extension().is_some_and(|ext| ext == "json") {
+        // the pack is written out again as a whole, so it comes back in the
+        // standard layout and field order, with the language filled in
+        let mut json_pack: WordPack = serde_json::from_str(&fs::read_to_string(pack)?)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+        json_pack.words.extend(added.iter().cloned().map(WordEntry::Plain));
+        let contents = serde_json::to_string_pretty(&json_pack)?;
+        fs::write(pack, contents + "\n")?;
+        writeln!(out, "{} is opnieuw netjes opgemaakt", file_name(pack))?;
+    } else {
+        // a text pack gets the new words at the end, on their own lines
+        let existing = fs::read_to_string(pack).unwrap_or_default();
+        let mut file = fs::OpenOptions::new().create(true).append(true).open(pack)?;
+        if !existing.is_empty() && !existing.ends_with('\n') {
+            writeln!(file)?;
+        }
+        for word in &added {
+            writeln!(file, "{}", word)?;
+        }
+    }
+
+    let words = |count: usize| match count {
+        1 => "1 woord".to_string(),
+        _ => format!("{} woorden", count),
+    };
+    writeln!(
+        out,
+        "{} toegevoegd aan {}, {} stond(en) er al in",
+        words(added.len()),
+        file_name(pack),
+        words(already_there)
+    )?;
+    Ok(())
+}